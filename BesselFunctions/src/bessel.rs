@@ -0,0 +1,451 @@
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+#![allow(clippy::many_single_char_names)]
+
+use core::f64;
+use std::f64::consts::{FRAC_2_PI, PI};
+
+const GAMMA: f64 = 0.577_215_664_901_532_9;
+
+/// Maximum absolute value of the order, the functions of higher orders are NaN
+pub const MAX_ORDER: i32 = 100;
+
+// Relative tolerance of the series
+const TOLERANCE: f64 = 1e-16;
+const MAX_TERMS: i32 = 1_000;
+
+// Power series of J0, J1, Y0, Y1 are used below this argument,
+// asymptotic expansions above it
+const ASYMPTOTIC_X: f64 = 17.0;
+
+// Power series of K0 and K1 are used below this argument
+const K_SERIES_X: f64 = 2.0;
+
+// Step of the trapezoidal rule for the integral representation of K
+const K_STEP: f64 = 0.05;
+
+// The recurrences and series below are only run for orders up to MAX_ORDER
+fn valid_order(n: i32) -> bool {
+    n.unsigned_abs() <= MAX_ORDER.unsigned_abs()
+}
+
+// Sign of the function of order n with the symmetry f(-n) = (-1)^n f(n)
+fn parity(n: i32) -> f64 {
+    if n % 2 == 0 { 1.0 } else { -1.0 }
+}
+
+// Power series of the Bessel function of the first kind of integer order n >= 0:
+// J_n(x) = sum (-1)^k (x/2)^(2k+n) / (k! (k+n)!)
+fn j_series(n: i32, x: f64) -> f64 {
+    let x2 = x * x / 4.0;
+
+    let mut a = (1..=n).fold(1.0, |a, i| a * x / 2.0 / f64::from(i));
+    let mut s = a;
+    for k in 1..MAX_TERMS {
+        a *= -x2 / (f64::from(k) * f64::from(k + n));
+        s += a;
+        if a.abs() <= TOLERANCE * s.abs() {
+            break;
+        }
+    }
+    s
+}
+
+// Hankel's asymptotic expansion for large x of the functions of the first
+// and second kind of order nu: (J_nu(x), Y_nu(x))
+fn jy_asymptotic(nu: f64, x: f64) -> (f64, f64) {
+    let mu = 4.0 * nu * nu;
+
+    // P = sum (-1)^k a_2k / x^2k, Q = sum (-1)^k a_2k+1 / x^(2k+1),
+    // a_k = (mu - 1^2)(mu - 3^2)...(mu - (2k-1)^2) / (k! 8^k)
+    let mut p = 1.0;
+    let mut q = 0.0;
+    let mut a: f64 = 1.0;
+    for k in 1..MAX_TERMS {
+        let odd = f64::from(2 * k - 1);
+        let next = a * (mu - odd * odd) / (f64::from(k) * 8.0 * x);
+
+        // Series diverges after the smallest term
+        if next.abs() >= a.abs() && k > 1 {
+            break;
+        }
+        a = next;
+
+        let sign = if (k / 2) % 2 == 0 { 1.0 } else { -1.0 };
+        if k % 2 == 0 {
+            p += sign * a;
+        } else {
+            q += sign * a;
+        }
+
+        if a.abs() < TOLERANCE {
+            break;
+        }
+    }
+
+    let chi = x - (nu / 2.0 + 0.25) * PI;
+    let c = (FRAC_2_PI / x).sqrt();
+    (
+        c * (p * chi.cos() - q * chi.sin()),
+        c * (p * chi.sin() + q * chi.cos()),
+    )
+}
+
+/// Bessel function of the first kind of 0-th order J0(x)
+pub fn j0(x: f64) -> f64 {
+    let x = x.abs();
+    if x < ASYMPTOTIC_X {
+        j_series(0, x)
+    } else {
+        jy_asymptotic(0.0, x).0
+    }
+}
+
+/// Bessel function of the first kind of 1-st order J1(x)
+pub fn j1(x: f64) -> f64 {
+    if x < 0.0 {
+        -j1(-x)
+    } else if x < ASYMPTOTIC_X {
+        j_series(1, x)
+    } else {
+        jy_asymptotic(1.0, x).0
+    }
+}
+
+/// Bessel function of the first kind of integer order J_n(x), |n| <= MAX_ORDER
+pub fn jn(n: i32, x: f64) -> f64 {
+    if !valid_order(n) {
+        return f64::NAN;
+    }
+    if n < 0 {
+        return parity(n) * jn(-n, x);
+    }
+    if x < 0.0 {
+        return parity(n) * jn(n, -x);
+    }
+
+    match n {
+        0 => j0(x),
+        1 => j1(x),
+        _ if x == 0.0 => 0.0,
+        // Series has no cancellation of large terms for small arguments
+        _ if x < ASYMPTOTIC_X => j_series(n, x),
+        // Forward recurrence J_k+1 = 2k/x J_k - J_k-1 is stable for k < x
+        _ if f64::from(n) < x => {
+            let (mut j_prev, mut j) = (j0(x), j1(x));
+            for k in 1..n {
+                (j_prev, j) = (j, 2.0 * f64::from(k) / x * j - j_prev);
+            }
+            j
+        }
+        _ => jn_backward(n, x),
+    }
+}
+
+// Miller's backward recurrence for the order n > x. The values are normalized
+// by the sum 1 = J0 + 2 (J2 + J4 + ...)
+fn jn_backward(n: i32, x: f64) -> f64 {
+    const EXTRA_ORDERS: f64 = 40.0;
+
+    // Start from an even order well above n, where J is negligible
+    let m = 2 * ((n + (EXTRA_ORDERS * f64::from(n)).sqrt() as i32 + 20) / 2);
+
+    let (mut j_next, mut j) = (0.0, 1e-300);
+    let mut sum = 0.0;
+    let mut result = 0.0;
+    for k in (1..=m).rev() {
+        (j_next, j) = (j, 2.0 * f64::from(k) / x * j - j_next);
+
+        // Rescale to avoid overflow
+        if j.abs() > 1e250 {
+            j *= 1e-250;
+            j_next *= 1e-250;
+            sum *= 1e-250;
+            result *= 1e-250;
+        }
+
+        // Now j = J_(k-1)
+        if k - 1 == n {
+            result = j;
+        }
+        if (k - 1) % 2 == 0 && k > 1 {
+            sum += 2.0 * j;
+        }
+    }
+    sum += j;
+
+    result / sum
+}
+
+// Harmonic numbers H_k = 1 + 1/2 + ... + 1/k
+fn harmonic(k: i32) -> f64 {
+    (1..=k).map(|i| 1.0 / f64::from(i)).sum()
+}
+
+/// Bessel function of the second kind of 0-th order Y0(x), x > 0
+pub fn y0(x: f64) -> f64 {
+    if x <= 0.0 {
+        return f64::NAN;
+    }
+    if x >= ASYMPTOTIC_X {
+        return jy_asymptotic(0.0, x).1;
+    }
+
+    // Y0 = 2/pi ((ln(x/2) + gamma) J0 + sum (-1)^(k+1) H_k (x/2)^2k / (k!)^2)
+    let x2 = x * x / 4.0;
+    let mut a = 1.0;
+    let mut s = 0.0;
+    for k in 1..MAX_TERMS {
+        a *= -x2 / f64::from(k * k);
+        let term = -a * harmonic(k);
+        s += term;
+        if term.abs() <= TOLERANCE * s.abs() {
+            break;
+        }
+    }
+
+    FRAC_2_PI * (((x / 2.0).ln() + GAMMA) * j0(x) + s)
+}
+
+/// Bessel function of the second kind of 1-st order Y1(x), x > 0
+pub fn y1(x: f64) -> f64 {
+    if x <= 0.0 {
+        return f64::NAN;
+    }
+    if x >= ASYMPTOTIC_X {
+        return jy_asymptotic(1.0, x).1;
+    }
+
+    // Y1 = 2/pi ln(x/2) J1 - 2/(pi x)
+    //      - 1/pi sum (-1)^k (psi(k+1) + psi(k+2)) (x/2)^(2k+1) / (k! (k+1)!),
+    // psi(k+1) = H_k - gamma
+    let x2 = x * x / 4.0;
+    let mut a = x / 2.0;
+    let mut s = 0.0;
+    for k in 0..MAX_TERMS {
+        if k > 0 {
+            a *= -x2 / f64::from(k * (k + 1));
+        }
+        let term = a * (harmonic(k) + harmonic(k + 1) - 2.0 * GAMMA);
+        s += term;
+        if term.abs() <= TOLERANCE * s.abs() {
+            break;
+        }
+    }
+
+    FRAC_2_PI * (x / 2.0).ln() * j1(x) - FRAC_2_PI / x - s / PI
+}
+
+/// Bessel function of the second kind of integer order Y_n(x), x > 0, |n| <= MAX_ORDER
+pub fn yn(n: i32, x: f64) -> f64 {
+    if !valid_order(n) {
+        return f64::NAN;
+    }
+    if n < 0 {
+        return parity(n) * yn(-n, x);
+    }
+    if n == 0 {
+        return y0(x);
+    }
+
+    // Forward recurrence is stable for the growing functions of the second kind
+    let (mut y_prev, mut y) = (y0(x), y1(x));
+    for k in 1..n {
+        (y_prev, y) = (y, 2.0 * f64::from(k) / x * y - y_prev);
+    }
+    y
+}
+
+/// Modified Bessel function of the first kind of integer order I_n(x), |n| <= MAX_ORDER
+pub fn i_n(n: i32, x: f64) -> f64 {
+    if !valid_order(n) {
+        return f64::NAN;
+    }
+    // I_-n = I_n for integer order
+    let n = n.abs();
+    if x < 0.0 {
+        return parity(n) * i_n(n, -x);
+    }
+
+    // Series of positive terms: I_n(x) = sum (x/2)^(2k+n) / (k! (k+n)!)
+    let x2 = x * x / 4.0;
+    let mut a = (1..=n).fold(1.0, |a, i| a * x / 2.0 / f64::from(i));
+    let mut s = a;
+    for k in 1..MAX_TERMS {
+        a *= x2 / (f64::from(k) * f64::from(k + n));
+        s += a;
+        if a <= TOLERANCE * s {
+            break;
+        }
+    }
+    s
+}
+
+// Integral representation K_nu(x) = int_0^inf exp(-x cosh t) cosh(nu t) dt.
+// Trapezoidal rule converges fast for the smooth decaying integrand
+fn k_integral(nu: f64, x: f64) -> f64 {
+    let f = |t: f64| (-x * t.cosh()).exp() * (nu * t).cosh();
+
+    let mut s = f(0.0) / 2.0;
+    for i in 1.. {
+        let y = f(K_STEP * f64::from(i));
+        s += y;
+        if y <= TOLERANCE * s {
+            break;
+        }
+    }
+    s * K_STEP
+}
+
+/// Modified Bessel function of the second kind of 0-th order K0(x), x > 0
+pub fn k0(x: f64) -> f64 {
+    if x <= 0.0 {
+        return f64::NAN;
+    }
+    if x > K_SERIES_X {
+        return k_integral(0.0, x);
+    }
+
+    // K0 = -(ln(x/2) + gamma) I0 + sum H_k (x/2)^2k / (k!)^2
+    let x2 = x * x / 4.0;
+    let mut a = 1.0;
+    let mut s = 0.0;
+    for k in 1..MAX_TERMS {
+        a *= x2 / f64::from(k * k);
+        let term = a * harmonic(k);
+        s += term;
+        if term <= TOLERANCE * s {
+            break;
+        }
+    }
+
+    -((x / 2.0).ln() + GAMMA) * i_n(0, x) + s
+}
+
+/// Modified Bessel function of the second kind of 1-st order K1(x), x > 0
+pub fn k1(x: f64) -> f64 {
+    if x <= 0.0 {
+        return f64::NAN;
+    }
+    if x > K_SERIES_X {
+        return k_integral(1.0, x);
+    }
+
+    // K1 = ln(x/2) I1 + 1/x
+    //      - 1/2 sum (psi(k+1) + psi(k+2)) (x/2)^(2k+1) / (k! (k+1)!)
+    let x2 = x * x / 4.0;
+    let mut a = x / 2.0;
+    let mut s = 0.0;
+    for k in 0..MAX_TERMS {
+        if k > 0 {
+            a *= x2 / f64::from(k * (k + 1));
+        }
+        let term = a * (harmonic(k) + harmonic(k + 1) - 2.0 * GAMMA);
+        s += term;
+        if term.abs() <= TOLERANCE * s.abs() {
+            break;
+        }
+    }
+
+    (x / 2.0).ln() * i_n(1, x) + 1.0 / x - s / 2.0
+}
+
+/// Modified Bessel function of the second kind of integer order K_n(x), x > 0,
+/// |n| <= MAX_ORDER
+pub fn k_n(n: i32, x: f64) -> f64 {
+    if !valid_order(n) {
+        return f64::NAN;
+    }
+    // K_-n = K_n
+    let n = n.abs();
+    if n == 0 {
+        return k0(x);
+    }
+
+    // Forward recurrence K_k+1 = 2k/x K_k + K_k-1 is stable
+    let (mut k_prev, mut k) = (k0(x), k1(x));
+    for i in 1..n {
+        (k_prev, k) = (k, 2.0 * f64::from(i) / x * k + k_prev);
+    }
+    k
+}
+
+/// Spherical Bessel function of the first kind of order 0 <= n <= MAX_ORDER:
+/// j_n(x) = sqrt(pi / 2x) J_(n+1/2)(x)
+pub fn sph_jn(n: i32, x: f64) -> f64 {
+    if !(0..=MAX_ORDER).contains(&n) {
+        return f64::NAN;
+    }
+    if x < 0.0 {
+        return parity(n) * sph_jn(n, -x);
+    }
+    if x == 0.0 {
+        return if n == 0 { 1.0 } else { 0.0 };
+    }
+
+    let j0 = x.sin() / x;
+    let j1 = x.sin() / (x * x) - x.cos() / x;
+    match n {
+        0 => return j0,
+        1 => return j1,
+        _ => {}
+    }
+
+    // Forward recurrence j_k+1 = (2k+1)/x j_k - j_k-1 is stable for k < x
+    if f64::from(n) < x {
+        let (mut j_prev, mut j) = (j0, j1);
+        for k in 1..n {
+            (j_prev, j) = (j, f64::from(2 * k + 1) / x * j - j_prev);
+        }
+        return j;
+    }
+
+    // Backward recurrence normalized by the larger of j0 and j1
+    let m = n + (40.0 * f64::from(n)).sqrt() as i32 + 20;
+    let (mut j_next, mut j) = (0.0, 1e-300);
+    let mut result = 0.0;
+    let (mut b0, mut b1) = (0.0, 0.0);
+    for k in (1..=m).rev() {
+        (j_next, j) = (j, f64::from(2 * k + 1) / x * j - j_next);
+
+        if j.abs() > 1e250 {
+            j *= 1e-250;
+            j_next *= 1e-250;
+            result *= 1e-250;
+        }
+
+        // Now j = j_(k-1) and j_next = j_k
+        if k - 1 == n {
+            result = j;
+        }
+        if k == 1 {
+            (b0, b1) = (j, j_next);
+        }
+    }
+
+    if j0.abs() > j1.abs() {
+        result * j0 / b0
+    } else {
+        result * j1 / b1
+    }
+}
+
+/// Spherical Bessel function of the second kind of order 0 <= n <= MAX_ORDER, x > 0:
+/// y_n(x) = sqrt(pi / 2x) Y_(n+1/2)(x)
+pub fn sph_yn(n: i32, x: f64) -> f64 {
+    if !(0..=MAX_ORDER).contains(&n) || x <= 0.0 {
+        return f64::NAN;
+    }
+
+    let y0 = -x.cos() / x;
+    if n == 0 {
+        return y0;
+    }
+
+    let (mut y_prev, mut y) = (y0, -x.cos() / (x * x) - x.sin() / x);
+    for k in 1..n {
+        (y_prev, y) = (y, f64::from(2 * k + 1) / x * y - y_prev);
+    }
+    y
+}
@@ -0,0 +1,245 @@
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::many_single_char_names)]
+
+use core::f64;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+const GAMMA: f64 = 0.577_215_664_9;
+const PI_POW_2: f64 = f64::consts::PI * f64::consts::PI;
+const DELTA: f64 = 1e-6;
+const MAX_ITER: i64 = 10_000;
+
+// Limits of the adaptive Simpson's rule
+const MAX_DEPTH: u32 = 50;
+const MIN_TOLERANCE: f64 = 1e-15;
+
+type Constants = [f64];
+type IntegrationFunc = fn(f64, &Constants) -> f64;
+// Nodes and weights of the Gauss-Legendre rule
+type GaussRule = Arc<[(f64, f64)]>;
+
+/// Quadrature rule of the integrals
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Quadrature {
+    /// Composite Simpson's rule with the number of pairs of subintervals
+    Simpson(u32),
+    /// Gauss-Legendre rule with the number of nodes
+    GaussLegendre(u32),
+    /// Adaptive Simpson's rule with the absolute tolerance of the error estimate
+    AdaptiveSimpson(f64),
+}
+
+/// Default rule is the composite Simpson's rule of `y0_1` and `j0`. It is not
+/// the fastest one, the other rules are chosen explicitly for the comparison
+impl Default for Quadrature {
+    fn default() -> Self {
+        Quadrature::Simpson(MAX_ITER as u32)
+    }
+}
+
+impl fmt::Display for Quadrature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Quadrature::Simpson(n) => write!(f, "Simpson, {n}"),
+            Quadrature::GaussLegendre(n) => write!(f, "Gauss-Legendre, {n}"),
+            Quadrature::AdaptiveSimpson(eps) => write!(f, "Adaptive Simpson, {eps:e}"),
+        }
+    }
+}
+
+fn simpson(x1: f64, x2: f64, scale: u32, f: IntegrationFunc, c: &Constants) -> f64 {
+    let div_count = scale.max(1) * 2; // Always even number of subintervals
+
+    let dx: f64 = (x2 - x1) / f64::from(div_count);
+    let mut s: f64 = 0.0;
+    for i in 0..=div_count {
+        let x = x1 + dx * f64::from(i);
+        let y = f(x, c);
+        let m = if i > 0 && i < div_count {
+            f64::from((i % 2 + 1) * 2)
+        } else {
+            1.0
+        };
+        s += y * m;
+    }
+
+    s * dx / 3.0
+}
+
+// Nodes and weights of the Gauss-Legendre rule on [-1, 1]. Nodes are the roots
+// of the Legendre polynomial found by Newton's method
+fn gauss_legendre_nodes(n: u32) -> Vec<(f64, f64)> {
+    let nf = f64::from(n);
+    (1..=n)
+        .map(|i| {
+            let mut x = (f64::consts::PI * (f64::from(i) - 0.25) / (nf + 0.5)).cos();
+            let mut dp = 1.0;
+            for _ in 0..100 {
+                // P_n(x) and P_n-1(x) by the recurrence relation
+                let (mut p0, mut p1) = (1.0, x);
+                for k in 2..=n {
+                    let k = f64::from(k);
+                    (p0, p1) = (p1, ((2.0 * k - 1.0) * x * p1 - (k - 1.0) * p0) / k);
+                }
+                let (p, p_prev) = if n == 1 { (x, 1.0) } else { (p1, p0) };
+
+                dp = nf * (x * p - p_prev) / (x * x - 1.0);
+                let dx = p / dp;
+                x -= dx;
+                if dx.abs() < 1e-16 {
+                    break;
+                }
+            }
+            (x, 2.0 / ((1.0 - x * x) * dp * dp))
+        })
+        .collect()
+}
+
+// Rules are computed once for each number of nodes and shared by all integrals
+fn gauss_legendre_rule(n: u32) -> GaussRule {
+    static RULES: OnceLock<Mutex<HashMap<u32, GaussRule>>> = OnceLock::new();
+
+    let mut rules = RULES
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    rules
+        .entry(n)
+        .or_insert_with(|| gauss_legendre_nodes(n).into())
+        .clone()
+}
+
+fn gauss_legendre(x1: f64, x2: f64, nodes: u32, f: IntegrationFunc, c: &Constants) -> f64 {
+    let half = (x2 - x1) / 2.0;
+    let mid = (x2 + x1) / 2.0;
+    gauss_legendre_rule(nodes.max(1))
+        .iter()
+        .map(|&(x, w)| w * f(mid + half * x, c))
+        .sum::<f64>()
+        * half
+}
+
+// Interval is halved until the difference of Simpson's rule on the interval and
+// on the halves is within the tolerance. The difference is used as the correction.
+// Points are the ends and the middle of the interval with the values of the function
+fn adaptive_simpson_step(
+    points: [(f64, f64); 3],
+    whole: f64,
+    eps: f64,
+    depth: u32,
+    f: IntegrationFunc,
+    c: &Constants,
+) -> f64 {
+    let [(x1, y1), (xm, ym), (x2, y2)] = points;
+    let (xl, xr) = ((x1 + xm) / 2.0, (xm + x2) / 2.0);
+    let (yl, yr) = (f(xl, c), f(xr, c));
+    let left = (xm - x1) / 6.0 * (y1 + 4.0 * yl + ym);
+    let right = (x2 - xm) / 6.0 * (ym + 4.0 * yr + y2);
+    let delta = left + right - whole;
+
+    if depth == 0 || delta.abs() <= 15.0 * eps {
+        return left + right + delta / 15.0;
+    }
+
+    adaptive_simpson_step(
+        [(x1, y1), (xl, yl), (xm, ym)],
+        left,
+        eps / 2.0,
+        depth - 1,
+        f,
+        c,
+    ) + adaptive_simpson_step(
+        [(xm, ym), (xr, yr), (x2, y2)],
+        right,
+        eps / 2.0,
+        depth - 1,
+        f,
+        c,
+    )
+}
+
+fn adaptive_simpson(x1: f64, x2: f64, eps: f64, f: IntegrationFunc, c: &Constants) -> f64 {
+    let xm = (x1 + x2) / 2.0;
+    let points = [(x1, f(x1, c)), (xm, f(xm, c)), (x2, f(x2, c))];
+    let whole = (x2 - x1) / 6.0 * (points[0].1 + 4.0 * points[1].1 + points[2].1);
+    adaptive_simpson_step(points, whole, eps.max(MIN_TOLERANCE), MAX_DEPTH, f, c)
+}
+
+fn integrate(x1: f64, x2: f64, quadrature: Quadrature, f: IntegrationFunc, c: &Constants) -> f64 {
+    match quadrature {
+        Quadrature::Simpson(n) => simpson(x1, x2, n, f, c),
+        Quadrature::GaussLegendre(n) => gauss_legendre(x1, x2, n, f, c),
+        Quadrature::AdaptiveSimpson(eps) => adaptive_simpson(x1, x2, eps, f, c),
+    }
+}
+
+// Bessel Function of the Second Kind, 0-th order
+// Method 1: Integration
+
+fn d_y0_1(theta: f64, c: &Constants) -> f64 {
+    let x: f64 = c[0];
+
+    let sin_theta: f64 = theta.sin();
+    (x * theta.cos()).cos() * (GAMMA + (2.0 * x * sin_theta * sin_theta).ln())
+}
+
+// Integral of d_y0_1 over [0, DELTA] with the integrand expanded at theta = 0:
+// cos(x) (GAMMA + ln(2 x) + 2 ln(theta))
+fn y0_1_singular_part(x: f64) -> f64 {
+    x.cos() * ((GAMMA + (2.0 * x).ln()) * DELTA + 2.0 * DELTA * (DELTA.ln() - 1.0))
+}
+
+/// Bessel function of the second kind Y0(x) calculated by integration
+pub fn y0_1(x: f64) -> f64 {
+    y0_integral(x, Quadrature::default())
+}
+
+/// Bessel function of the second kind Y0(x) calculated by integration with
+/// the quadrature rule. Logarithmic singularity of the integrand near zero
+/// is integrated analytically
+pub fn y0_integral(x: f64, quadrature: Quadrature) -> f64 {
+    (integrate(DELTA, f64::consts::PI / 2.0, quadrature, d_y0_1, &[x]) + y0_1_singular_part(x))
+        * 4.0
+        / PI_POW_2
+}
+
+// Bessel Function of the Second Kind, 0-th order
+// Method 2: Series
+
+// Bessel Function of the First Kind, 0-th order
+fn d_j0(theta: f64, c: &Constants) -> f64 {
+    let x: f64 = c[0];
+    (theta.sin() * x).cos()
+}
+
+/// Bessel function of the first kind J0(x) calculated by integration
+pub fn j0(x: f64) -> f64 {
+    integrate(0.0, f64::consts::PI, Quadrature::default(), d_j0, &[x]) / f64::consts::PI
+}
+
+/// Bessel function of the second kind Y0(x) calculated by infinite series
+pub fn y0_2(x: f64) -> f64 {
+    let mut s: f64 = 0.0;
+
+    let dm: f64 = x * x / 4.0;
+    let mut m: f64 = dm;
+    let mut n: f64 = 1.0;
+    let mut p: f64 = 1.0;
+
+    for dn in 2..MAX_ITER {
+        let a = p * m / (n * n);
+        s += a;
+
+        if a.abs() < DELTA {
+            break;
+        }
+
+        m *= -dm;
+        n *= dn as f64;
+        p += 1.0 / (dn as f64);
+    }
+
+    (((x / 2.0).ln() + GAMMA) * j0(x) + s) * 2.0 / f64::consts::PI
+}
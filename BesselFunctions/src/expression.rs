@@ -0,0 +1,443 @@
+#![allow(clippy::cast_possible_truncation)]
+
+use std::fmt;
+
+use crate::{bessel, bessel_func};
+
+/// Error of parsing of the expression with the position of the character in the text
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// Expression ends before the operand or the closing parenthesis
+    UnexpectedEnd,
+    /// Character or token is not expected at the position
+    Unexpected { token: String, position: usize },
+    /// Name is not a variable, constant or function
+    UnknownName { name: String, position: usize },
+    /// Order in the name of the function exceeds `bessel::MAX_ORDER`
+    OrderOutOfRange { name: String, position: usize },
+    /// Parentheses, signs, powers or operands of `+ - * /` chains are nested
+    /// deeper than `MAX_DEPTH`
+    TooDeep { position: usize },
+    /// Function is called with the wrong number of arguments
+    WrongArguments {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedEnd => write!(f, "Unexpected end of the expression"),
+            ParseError::Unexpected { token, position } => {
+                write!(f, "Unexpected '{token}' at position {}", position + 1)
+            }
+            ParseError::UnknownName { name, position } => {
+                write!(f, "Unknown name '{name}' at position {}", position + 1)
+            }
+            ParseError::OrderOutOfRange { name, position } => write!(
+                f,
+                "Order of '{name}' at position {} exceeds {}",
+                position + 1,
+                bessel::MAX_ORDER
+            ),
+            ParseError::TooDeep { position } => {
+                write!(
+                    f,
+                    "Expression is nested too deeply at position {}",
+                    position + 1
+                )
+            }
+            ParseError::WrongArguments {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Function {name} expects {expected} argument(s), found {found}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+type Function = fn(f64) -> f64;
+type OrderFunction = fn(i32, f64) -> f64;
+
+// Elementary functions and functions of the second kind of 0-th order
+// calculated by integration and by series
+const FUNCTIONS: [(&str, Function); 16] = [
+    ("sin", f64::sin),
+    ("cos", f64::cos),
+    ("tan", f64::tan),
+    ("asin", f64::asin),
+    ("acos", f64::acos),
+    ("atan", f64::atan),
+    ("sinh", f64::sinh),
+    ("cosh", f64::cosh),
+    ("tanh", f64::tanh),
+    ("exp", f64::exp),
+    ("ln", f64::ln),
+    ("log10", f64::log10),
+    ("sqrt", f64::sqrt),
+    ("abs", f64::abs),
+    ("y0_1", bessel_func::y0_1),
+    ("y0_2", bessel_func::y0_2),
+];
+
+// Bessel functions of integer order called as Jn(x) or J(n, x)
+const ORDER_FUNCTIONS: [(&str, OrderFunction); 6] = [
+    ("J", bessel::jn),
+    ("Y", bessel::yn),
+    ("I", bessel::i_n),
+    ("K", bessel::k_n),
+    ("j", bessel::sph_jn),
+    ("y", bessel::sph_yn),
+];
+
+/// Maximum nesting depth of the operands, deeper expressions would overflow
+/// the stack of the recursive parser and evaluation. Each operand of a chain
+/// of `+ - * /` operators is one level deeper than the previous one
+pub const MAX_DEPTH: usize = 200;
+
+const CONSTANTS: [(&str, f64); 2] = [("pi", std::f64::consts::PI), ("e", std::f64::consts::E)];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Operator(char),
+    Open,
+    Close,
+    Comma,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(v) => write!(f, "{v}"),
+            Token::Name(name) => write!(f, "{name}"),
+            Token::Operator(c) => write!(f, "{c}"),
+            Token::Open => write!(f, "("),
+            Token::Close => write!(f, ")"),
+            Token::Comma => write!(f, ","),
+        }
+    }
+}
+
+// Tokens with the positions of their first characters
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        i += 1;
+
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '+' | '-' | '*' | '/' | '^' => Token::Operator(c),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            c if c.is_ascii_digit() || c == '.' => {
+                // Mantissa with the optional exponent
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                    let sign = usize::from(i + 1 < chars.len() && "+-".contains(chars[i + 1]));
+                    if i + 1 + sign < chars.len() && chars[i + 1 + sign].is_ascii_digit() {
+                        i += 1 + sign;
+                        while i < chars.len() && chars[i].is_ascii_digit() {
+                            i += 1;
+                        }
+                    }
+                }
+
+                let number: String = chars[start..i].iter().collect();
+                Token::Number(number.parse().map_err(|_| ParseError::Unexpected {
+                    token: number.clone(),
+                    position: start,
+                })?)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                Token::Name(chars[start..i].iter().collect())
+            }
+            c => {
+                return Err(ParseError::Unexpected {
+                    token: c.to_string(),
+                    position: start,
+                });
+            }
+        };
+        tokens.push((token, start));
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Number(f64),
+    Variable,
+    Negate(Box<Node>),
+    Binary(char, Box<Node>, Box<Node>),
+    Call(Function, Box<Node>),
+    // Function of the order given by the expression rounded to the integer,
+    // NaN for the orders that are not finite or exceed bessel::MAX_ORDER
+    CallOrder(OrderFunction, Box<Node>, Box<Node>),
+}
+
+impl Node {
+    fn eval(&self, x: f64) -> f64 {
+        match self {
+            Node::Number(v) => *v,
+            Node::Variable => x,
+            Node::Negate(a) => -a.eval(x),
+            Node::Binary(op, a, b) => {
+                let (a, b) = (a.eval(x), b.eval(x));
+                match op {
+                    '+' => a + b,
+                    '-' => a - b,
+                    '*' => a * b,
+                    '/' => a / b,
+                    _ => a.powf(b),
+                }
+            }
+            Node::Call(f, a) => f(a.eval(x)),
+            Node::CallOrder(f, n, a) => {
+                let n = n.eval(x).round();
+                if n.abs() <= f64::from(bessel::MAX_ORDER) {
+                    f(n as i32, a.eval(x))
+                } else {
+                    f64::NAN
+                }
+            }
+        }
+    }
+}
+
+// Recursive descent parser of the grammar:
+//   sum     = product { ("+" | "-") product }
+//   product = unary { ("*" | "/") unary }
+//   unary   = ("-" | "+") unary | power
+//   power   = primary [ "^" unary ]
+//   primary = number | name | name "(" sum { "," sum } ")" | "(" sum ")"
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|t| &t.0)
+    }
+
+    fn next(&mut self) -> Result<(Token, usize), ParseError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or(ParseError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<(), ParseError> {
+        let (token, position) = self.next()?;
+        if token == *expected {
+            Ok(())
+        } else {
+            Err(ParseError::Unexpected {
+                token: token.to_string(),
+                position,
+            })
+        }
+    }
+
+    // Nodes of the tree are one level deeper, the depth is checked at the next token
+    fn descend(&mut self) -> Result<(), ParseError> {
+        if self.depth >= MAX_DEPTH
+            && let Some(&(_, position)) = self.tokens.get(self.pos)
+        {
+            return Err(ParseError::TooDeep { position });
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn sum(&mut self) -> Result<Node, ParseError> {
+        let depth = self.depth;
+        let mut node = self.product()?;
+        while let Some(&Token::Operator(op @ ('+' | '-'))) = self.peek() {
+            self.descend()?;
+            self.pos += 1;
+            node = Node::Binary(op, Box::new(node), Box::new(self.product()?));
+        }
+        self.depth = depth;
+        Ok(node)
+    }
+
+    fn product(&mut self) -> Result<Node, ParseError> {
+        let depth = self.depth;
+        let mut node = self.unary()?;
+        while let Some(&Token::Operator(op @ ('*' | '/'))) = self.peek() {
+            self.descend()?;
+            self.pos += 1;
+            node = Node::Binary(op, Box::new(node), Box::new(self.unary()?));
+        }
+        self.depth = depth;
+        Ok(node)
+    }
+
+    // Every nested operand is parsed here
+    fn unary(&mut self) -> Result<Node, ParseError> {
+        self.descend()?;
+        let node = match self.peek() {
+            Some(Token::Operator('-')) => {
+                self.pos += 1;
+                self.unary().map(|a| Node::Negate(Box::new(a)))
+            }
+            Some(Token::Operator('+')) => {
+                self.pos += 1;
+                self.unary()
+            }
+            _ => self.power(),
+        };
+        self.depth -= 1;
+        node
+    }
+
+    fn power(&mut self) -> Result<Node, ParseError> {
+        let base = self.primary()?;
+        if let Some(Token::Operator('^')) = self.peek() {
+            self.pos += 1;
+            return Ok(Node::Binary('^', Box::new(base), Box::new(self.unary()?)));
+        }
+        Ok(base)
+    }
+
+    fn arguments(&mut self) -> Result<Vec<Node>, ParseError> {
+        self.expect(&Token::Open)?;
+        let mut args = vec![self.sum()?];
+        while let Some(Token::Comma) = self.peek() {
+            self.pos += 1;
+            args.push(self.sum()?);
+        }
+        self.expect(&Token::Close)?;
+        Ok(args)
+    }
+
+    fn primary(&mut self) -> Result<Node, ParseError> {
+        let (token, position) = self.next()?;
+        match token {
+            Token::Number(v) => Ok(Node::Number(v)),
+            Token::Open => {
+                let node = self.sum()?;
+                self.expect(&Token::Close)?;
+                Ok(node)
+            }
+            Token::Name(name) => self.name(name, position),
+            token => Err(ParseError::Unexpected {
+                token: token.to_string(),
+                position,
+            }),
+        }
+    }
+
+    fn name(&mut self, name: String, position: usize) -> Result<Node, ParseError> {
+        if name == "x" {
+            return Ok(Node::Variable);
+        }
+        if let Some(&(_, v)) = CONSTANTS.iter().find(|c| c.0 == name) {
+            return Ok(Node::Number(v));
+        }
+
+        let check_count = |args: &Vec<Node>, expected: usize| {
+            if args.len() == expected {
+                Ok(())
+            } else {
+                Err(ParseError::WrongArguments {
+                    name: name.clone(),
+                    expected,
+                    found: args.len(),
+                })
+            }
+        };
+
+        if let Some(&(_, f)) = FUNCTIONS.iter().find(|c| c.0 == name) {
+            let mut args = self.arguments()?;
+            check_count(&args, 1)?;
+            return Ok(Node::Call(f, Box::new(args.remove(0))));
+        }
+
+        // Order is either a part of the name or the first argument
+        let (prefix, order) = name.split_at(name.chars().next().map_or(0, char::len_utf8));
+        if let Some(&(_, f)) = ORDER_FUNCTIONS.iter().find(|c| c.0 == prefix) {
+            if order.is_empty() {
+                let mut args = self.arguments()?;
+                check_count(&args, 2)?;
+                let x = args.remove(1);
+                return Ok(Node::CallOrder(f, Box::new(args.remove(0)), Box::new(x)));
+            }
+            if !order.is_empty() && order.chars().all(|c| c.is_ascii_digit()) {
+                let Some(order) = order
+                    .parse::<i32>()
+                    .ok()
+                    .filter(|&n| n <= bessel::MAX_ORDER)
+                else {
+                    return Err(ParseError::OrderOutOfRange { name, position });
+                };
+                let mut args = self.arguments()?;
+                check_count(&args, 1)?;
+                let order = Box::new(Node::Number(f64::from(order)));
+                return Ok(Node::CallOrder(f, order, Box::new(args.remove(0))));
+            }
+        }
+
+        Err(ParseError::UnknownName { name, position })
+    }
+}
+
+/// Expression of the variable `x` with arithmetic operators `+ - * / ^`, constants
+/// `pi` and `e`, elementary functions (`sin`, `exp`, `ln`, `sqrt`, etc.), Bessel functions
+/// of integer order `Jn(x)`, `Yn(x)`, `In(x)`, `Kn(x)`, spherical functions `jn(x)`,
+/// `yn(x)` (or with the order as the first argument: `J(n, x)`) and the functions
+/// of the second kind of 0-th order by integration `y0_1(x)` and by series `y0_2(x)`
+#[derive(Debug, Clone)]
+pub struct Expression {
+    root: Node,
+}
+
+impl Expression {
+    /// Parse the text of the expression, e.g. `x*J1(x) - 2*Y0(x)`
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            pos: 0,
+            depth: 0,
+        };
+        let root = parser.sum()?;
+
+        if let Some((token, position)) = parser.tokens.get(parser.pos) {
+            return Err(ParseError::Unexpected {
+                token: token.to_string(),
+                position: *position,
+            });
+        }
+
+        Ok(Self { root })
+    }
+
+    /// Value of the expression at `x`
+    pub fn eval(&self, x: f64) -> f64 {
+        self.root.eval(x)
+    }
+}
@@ -0,0 +1,11 @@
+//! Bessel functions of integer order: functions of the first and second kind,
+//! modified and spherical functions calculated with series, recurrence
+//! relations and asymptotic expansions, and the functions of the second kind
+//! of 0-th order calculated by integration and by infinite series.
+//! Zeros of the functions are found by bisection, user expressions with
+//! the functions are parsed and evaluated.
+
+pub mod bessel;
+pub mod bessel_func;
+pub mod expression;
+pub mod zeros;
@@ -0,0 +1,63 @@
+// Bisection stops at this width of the bracket
+const TOLERANCE: f64 = 1e-14;
+const MAX_ITERATIONS: i32 = 200;
+
+// Root of the function in the bracket [a, b] with f(a) and f(b) of different signs
+fn bisect<F: Fn(f64) -> f64>(f: &F, mut a: f64, mut b: f64, fa: f64) -> f64 {
+    let positive = fa > 0.0;
+    for _ in 0..MAX_ITERATIONS {
+        let c = 0.5 * (a + b);
+        if (b - a).abs() <= TOLERANCE * c.abs().max(1.0) {
+            break;
+        }
+
+        let fc = f(c);
+        if fc == 0.0 {
+            return c;
+        }
+        if (fc > 0.0) == positive {
+            a = c;
+        } else {
+            b = c;
+        }
+    }
+    0.5 * (a + b)
+}
+
+/// Zeros of the function on the interval [xmin, xmax] in ascending order.
+/// Sign changes are searched on the grid of `samples` subintervals and refined
+/// by bisection. Sign changes at poles are rejected, zeros closer than
+/// the step of the grid and zeros without a sign change may be missed
+pub fn find_zeros<F: Fn(f64) -> f64>(f: F, xmin: f64, xmax: f64, samples: u32) -> Vec<f64> {
+    let samples = samples.max(1);
+    let step = (xmax - xmin) / f64::from(samples);
+    let grid: Vec<(f64, f64)> = (0..=samples)
+        .map(|i| {
+            let x = xmin + step * f64::from(i);
+            (x, f(x))
+        })
+        .collect();
+
+    let mut zeros = vec![];
+    for (i, w) in grid.windows(2).enumerate() {
+        let ((a, fa), (b, fb)) = (w[0], w[1]);
+        if !fa.is_finite() || !fb.is_finite() {
+            continue;
+        }
+
+        if fa == 0.0 {
+            zeros.push(a);
+        } else if fa * fb < 0.0 {
+            let x = bisect(&f, a, b, fa);
+            // Function grows near the pole instead of vanishing
+            if f(x).abs() <= fa.abs().max(fb.abs()) {
+                zeros.push(x);
+            }
+        }
+
+        if i + 2 == grid.len() && fb == 0.0 {
+            zeros.push(b);
+        }
+    }
+    zeros
+}
@@ -0,0 +1,150 @@
+use bessel_functions::bessel;
+use bessel_functions::expression::{Expression, MAX_DEPTH, ParseError};
+
+const TOLERANCE: f64 = 1e-15;
+
+fn eval(text: &str, x: f64) -> f64 {
+    Expression::parse(text)
+        .unwrap_or_else(|e| panic!("{text}: {e}"))
+        .eval(x)
+}
+
+#[test]
+fn arithmetic() {
+    assert!((eval("1 + 2*3 - 4/8", 0.0) - 6.5).abs() < TOLERANCE);
+    assert!((eval("(1 + 2)*3", 0.0) - 9.0).abs() < TOLERANCE);
+    assert!((eval("-x^2", 3.0) + 9.0).abs() < TOLERANCE);
+    assert!((eval("2^3^2", 0.0) - 512.0).abs() < TOLERANCE);
+    assert!((eval("2^-1", 0.0) - 0.5).abs() < TOLERANCE);
+    assert!((eval("1.5e-3*x", 2.0) - 3e-3).abs() < TOLERANCE);
+}
+
+#[test]
+fn functions_and_constants() {
+    assert!((eval("sin(pi/2) + ln(e) + sqrt(abs(-4))", 0.0) - 4.0).abs() < TOLERANCE);
+    assert!((eval("exp(x)", 1.0) - std::f64::consts::E).abs() < TOLERANCE);
+}
+
+#[test]
+fn bessel_functions() {
+    let x = 3.7;
+    let expected = x * bessel::jn(1, x) - 2.0 * bessel::yn(0, x);
+    assert!((eval("x*J1(x) - 2*Y0(x)", x) - expected).abs() < TOLERANCE);
+    assert!((eval("J(2, x) - J2(x)", x)).abs() < TOLERANCE);
+    assert!(
+        (eval("K0(x) + I10(x) + j1(x) + y2(x)", x)
+            - (bessel::k_n(0, x)
+                + bessel::i_n(10, x)
+                + bessel::sph_jn(1, x)
+                + bessel::sph_yn(2, x)))
+        .abs()
+            < TOLERANCE
+    );
+    assert!((eval("y0_2(x) - Y0(x)", x)).abs() < 1e-3);
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Expression::parse("x +").unwrap_err(),
+        ParseError::UnexpectedEnd
+    );
+    assert_eq!(
+        Expression::parse("(x").unwrap_err(),
+        ParseError::UnexpectedEnd
+    );
+    assert_eq!(
+        Expression::parse("x $ 2").unwrap_err(),
+        ParseError::Unexpected {
+            token: "$".to_string(),
+            position: 2
+        }
+    );
+    assert_eq!(
+        Expression::parse("x )").unwrap_err(),
+        ParseError::Unexpected {
+            token: ")".to_string(),
+            position: 2
+        }
+    );
+    assert_eq!(
+        Expression::parse("2*foo(x)").unwrap_err(),
+        ParseError::UnknownName {
+            name: "foo".to_string(),
+            position: 2
+        }
+    );
+    assert_eq!(
+        Expression::parse("J(x)").unwrap_err(),
+        ParseError::WrongArguments {
+            name: "J".to_string(),
+            expected: 2,
+            found: 1
+        }
+    );
+    assert_eq!(
+        Expression::parse("J101(x)").unwrap_err(),
+        ParseError::OrderOutOfRange {
+            name: "J101".to_string(),
+            position: 0
+        }
+    );
+    assert_eq!(
+        Expression::parse("x + y99999999999(x)").unwrap_err(),
+        ParseError::OrderOutOfRange {
+            name: "y99999999999".to_string(),
+            position: 4
+        }
+    );
+}
+
+#[test]
+fn order_bounds() {
+    let max = bessel::MAX_ORDER;
+    assert!(!eval(&format!("J{max}(x)"), 5.0).is_nan());
+    assert!(!eval(&format!("J(-{max}, x)"), 5.0).is_nan());
+    for order in ["-3e9", "3e9", "1e300", "1/0", "0/0", &(max + 1).to_string()] {
+        let text = format!("J({order}, x)");
+        assert!(eval(&text, 5.0).is_nan(), "{text} is not NaN");
+    }
+}
+
+#[test]
+fn nesting_depth() {
+    let nested = |depth: usize| format!("{}x{}", "(".repeat(depth), ")".repeat(depth));
+    assert!((eval(&nested(MAX_DEPTH - 1), 2.0) - 2.0).abs() < TOLERANCE);
+    assert_eq!(
+        Expression::parse(&nested(MAX_DEPTH)).unwrap_err(),
+        ParseError::TooDeep {
+            position: MAX_DEPTH
+        }
+    );
+    assert!(matches!(
+        Expression::parse(&nested(200_000)),
+        Err(ParseError::TooDeep { .. })
+    ));
+    assert!(matches!(
+        Expression::parse(&"-".repeat(200_000)),
+        Err(ParseError::TooDeep { .. })
+    ));
+}
+
+// Chains of operators build trees as deep as the number of the operands
+#[test]
+fn long_chains() {
+    let chain = |op: &str, count: usize| vec!["x"; count].join(op);
+    assert!((eval(&chain("+", 100), 2.0) - 200.0).abs() < TOLERANCE);
+    assert!((eval(&chain("*", 10), 2.0) - 1024.0).abs() < TOLERANCE);
+
+    for op in ["+", "-", "*", "/"] {
+        let text = chain(op, 100_000);
+        assert!(
+            matches!(Expression::parse(&text), Err(ParseError::TooDeep { .. })),
+            "x{op}x... is parsed"
+        );
+    }
+    assert!(matches!(
+        Expression::parse(&format!("({})*x", chain("+", 1_000))),
+        Err(ParseError::TooDeep { .. })
+    ));
+}
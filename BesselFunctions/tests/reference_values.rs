@@ -0,0 +1,217 @@
+use bessel_functions::bessel;
+use bessel_functions::bessel_func::{self, Quadrature};
+
+// Tabulated values of the functions
+const J: [(i32, f64, f64); 9] = [
+    (0, 1.0, 0.765_197_686_557_966_6),
+    (0, 2.0, 0.223_890_779_141_235_67),
+    (0, 5.0, -0.177_596_771_314_338_3),
+    (0, 10.0, -0.245_935_764_451_348_3),
+    (1, 1.0, 0.440_050_585_744_933_55),
+    (1, 10.0, 0.043_472_746_168_861_44),
+    (2, 2.0, 0.352_834_028_615_637_73),
+    (5, 10.0, -0.234_061_528_186_793_65),
+    (10, 1.0, 2.630_615_123_687_453e-10),
+];
+
+const Y: [(i32, f64, f64); 5] = [
+    (0, 1.0, 0.088_256_964_215_676_97),
+    (0, 5.0, -0.308_517_625_249_033_8),
+    (0, 10.0, 0.055_671_167_283_599_39),
+    (1, 1.0, -0.781_212_821_300_288_7),
+    (1, 10.0, 0.249_015_424_206_953_88),
+];
+
+const I: [(i32, f64, f64); 3] = [
+    (0, 1.0, 1.266_065_877_752_008_2),
+    (1, 1.0, 0.565_159_103_992_485_1),
+    (3, 2.0, 0.212_739_959_239_852_7),
+];
+
+const K: [(i32, f64, f64); 4] = [
+    (0, 1.0, 0.421_024_438_240_708_34),
+    (1, 1.0, 0.601_907_230_197_234_6),
+    (0, 5.0, 0.003_691_098_334_042_594),
+    (2, 3.0, 0.061_510_458_471_742_04),
+];
+
+const SPH_J: [(i32, f64, f64); 3] = [
+    (0, 1.0, 0.841_470_984_807_896_5),
+    (1, 1.0, 0.301_168_678_939_756_74),
+    (3, 1.0, 0.009_006_581_117_112_516),
+];
+
+const SPH_Y: [(i32, f64, f64); 2] = [
+    (0, 1.0, -0.540_302_305_868_139_8),
+    (2, 1.0, -3.605_017_566_159_969),
+];
+
+// Relative tolerance of the library functions. Absolute tolerance
+// accounts for the rounding errors of the series near the zeros
+const TOLERANCE: f64 = 1e-12;
+const ABS_TOLERANCE: f64 = 1e-13;
+
+fn is_close(result: f64, value: f64) -> bool {
+    (result - value).abs() <= TOLERANCE * value.abs() + ABS_TOLERANCE
+}
+
+// Absolute tolerance of Y0 calculated by integration and by series
+const Y0_METHODS_TOLERANCE: f64 = 1e-3;
+
+// Absolute tolerance of Y0 calculated by the adaptive quadrature
+const ADAPTIVE_TOLERANCE: f64 = 1e-9;
+
+fn check(name: &str, f: fn(i32, f64) -> f64, table: &[(i32, f64, f64)]) {
+    for &(n, x, value) in table {
+        let result = f(n, x);
+        assert!(
+            is_close(result, value),
+            "{name}{n}({x}) = {result:e}, expected {value:e}"
+        );
+    }
+}
+
+#[test]
+fn first_kind() {
+    check("J", bessel::jn, &J);
+}
+
+#[test]
+fn second_kind() {
+    check("Y", bessel::yn, &Y);
+}
+
+#[test]
+fn modified_first_kind() {
+    check("I", bessel::i_n, &I);
+}
+
+#[test]
+fn modified_second_kind() {
+    check("K", bessel::k_n, &K);
+}
+
+#[test]
+fn spherical() {
+    check("j", bessel::sph_jn, &SPH_J);
+    check("y", bessel::sph_yn, &SPH_Y);
+}
+
+#[test]
+fn negative_order() {
+    for &(n, x, value) in &J {
+        let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+        assert!(is_close(bessel::jn(-n, x), sign * value));
+    }
+}
+
+// Recurrence and asymptotic expansion must agree with the integral
+// J_n(x) = 1/pi int_0^pi cos(n t - x sin t) dt at large orders and arguments
+#[test]
+fn large_order_and_argument() {
+    const POINTS: i32 = 20_000;
+
+    for n in [0, 3, 20, 45] {
+        for x in [12.0, 17.5, 30.0, 60.0] {
+            let h = std::f64::consts::PI / f64::from(POINTS);
+            let s: f64 = (0..=POINTS)
+                .map(|i| {
+                    let t = h * f64::from(i);
+                    let w = if i == 0 || i == POINTS { 0.5 } else { 1.0 };
+                    w * (f64::from(n) * t - x * t.sin()).cos()
+                })
+                .sum();
+            let value = s * h / std::f64::consts::PI;
+
+            let result = bessel::jn(n, x);
+            assert!(
+                (result - value).abs() < 1e-10,
+                "J{n}({x}) = {result:e}, expected {value:e}"
+            );
+        }
+    }
+}
+
+// Functions are finite at the maximum order and NaN above it, extreme orders
+// must neither overflow nor run long recurrences
+#[test]
+fn order_bounds() {
+    const X: f64 = 5.0;
+    let functions = [
+        ("J", bessel::jn as fn(i32, f64) -> f64),
+        ("Y", bessel::yn),
+        ("I", bessel::i_n),
+        ("K", bessel::k_n),
+        ("j", bessel::sph_jn),
+        ("y", bessel::sph_yn),
+    ];
+
+    for (name, f) in functions {
+        let result = f(bessel::MAX_ORDER, X);
+        assert!(!result.is_nan(), "{name}{}({X}) is NaN", bessel::MAX_ORDER);
+        for n in [
+            bessel::MAX_ORDER + 1,
+            -bessel::MAX_ORDER - 1,
+            i32::MAX,
+            i32::MIN,
+        ] {
+            assert!(f(n, X).is_nan(), "{name}{n}({X}) is not NaN");
+        }
+    }
+    assert!(!bessel::jn(-bessel::MAX_ORDER, X).is_nan());
+}
+
+#[test]
+fn wronskians() {
+    for n in 0..10 {
+        for i in 1..100 {
+            let x = 0.37 * f64::from(i);
+
+            // J_n+1 Y_n - J_n Y_n+1 = 2 / (pi x)
+            let w =
+                bessel::jn(n + 1, x) * bessel::yn(n, x) - bessel::jn(n, x) * bessel::yn(n + 1, x);
+            assert!((w * std::f64::consts::PI * x / 2.0 - 1.0).abs() < 1e-8);
+
+            // I_n K_n+1 + I_n+1 K_n = 1 / x
+            let w = bessel::i_n(n, x) * bessel::k_n(n + 1, x)
+                + bessel::i_n(n + 1, x) * bessel::k_n(n, x);
+            assert!((w * x - 1.0).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn y0_methods() {
+    for &(_, x, value) in Y.iter().filter(|v| v.0 == 0) {
+        for (name, f) in [
+            ("integration", bessel_func::y0_1 as fn(f64) -> f64),
+            ("series", bessel_func::y0_2),
+        ] {
+            let result = f(x);
+            assert!(
+                (result - value).abs() < Y0_METHODS_TOLERANCE,
+                "Y0({x}) by {name} = {result:e}, expected {value:e}"
+            );
+        }
+    }
+}
+
+#[test]
+fn y0_quadratures() {
+    for &(_, x, value) in Y.iter().filter(|v| v.0 == 0) {
+        for q in [
+            Quadrature::Simpson(10_000),
+            Quadrature::GaussLegendre(64),
+            Quadrature::AdaptiveSimpson(1e-10),
+        ] {
+            let result = bessel_func::y0_integral(x, q);
+            assert!(
+                (result - value).abs() < Y0_METHODS_TOLERANCE,
+                "Y0({x}) by {q} = {result:e}, expected {value:e}"
+            );
+        }
+
+        let result = bessel_func::y0_integral(x, Quadrature::AdaptiveSimpson(1e-10));
+        assert!((result - value).abs() < ADAPTIVE_TOLERANCE);
+    }
+}
@@ -0,0 +1,65 @@
+use bessel_functions::{bessel, zeros};
+
+// Tabulated zeros of the functions
+const J0_ZEROS: [f64; 4] = [
+    2.404_825_557_695_773,
+    5.520_078_110_286_311,
+    8.653_727_912_911_013,
+    11.791_534_439_014_281,
+];
+const J1_ZEROS: [f64; 4] = [
+    3.831_705_970_207_512_5,
+    7.015_586_669_815_619,
+    10.173_468_135_062_722,
+    13.323_691_936_314_223,
+];
+const Y0_ZEROS: [f64; 4] = [
+    0.893_576_966_279_167_5,
+    3.957_678_419_314_858,
+    7.086_051_060_301_773,
+    10.222_345_043_496_417,
+];
+
+const SAMPLES: u32 = 500;
+// Zeros are limited by the accuracy of the functions
+const TOLERANCE: f64 = 1e-11;
+
+fn check(name: &str, f: fn(i32, f64) -> f64, n: i32, expected: &[f64]) {
+    let zeros = zeros::find_zeros(|x| f(n, x), 0.0, 13.0, SAMPLES);
+    assert_eq!(zeros.len(), expected.len(), "{name}{n} zeros: {zeros:?}");
+    for (z, e) in zeros.iter().zip(expected) {
+        assert!(
+            (z - e).abs() < TOLERANCE,
+            "{name}{n} zero {z}, expected {e}"
+        );
+    }
+}
+
+#[test]
+fn bessel_zeros() {
+    check("J", bessel::jn, 0, &J0_ZEROS);
+    check(
+        "J",
+        bessel::jn,
+        1,
+        &[0.0, J1_ZEROS[0], J1_ZEROS[1], J1_ZEROS[2]],
+    );
+    check("Y", bessel::yn, 0, &Y0_ZEROS);
+}
+
+#[test]
+fn zeros_in_range() {
+    let zeros = zeros::find_zeros(|x| bessel::jn(0, x), 5.0, 12.0, SAMPLES);
+    assert_eq!(zeros.len(), 3);
+    assert!((zeros[2] - J0_ZEROS[3]).abs() < TOLERANCE);
+
+    assert!(zeros::find_zeros(|x| bessel::i_n(1, x) - 10.0, 0.0, 1.0, SAMPLES).is_empty());
+}
+
+#[test]
+fn poles_are_rejected() {
+    let zeros = zeros::find_zeros(f64::tan, 0.0, 4.0, SAMPLES);
+    assert_eq!(zeros.len(), 2, "{zeros:?}");
+    assert!(zeros[0].abs() < TOLERANCE);
+    assert!((zeros[1] - std::f64::consts::PI).abs() < TOLERANCE);
+}
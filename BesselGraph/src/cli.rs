@@ -0,0 +1,117 @@
+use std::fs;
+
+use bessel_functions::bessel;
+use bessel_functions::expression::Expression;
+use fltk::enums;
+
+use crate::export;
+use crate::plots::{
+    self, EXPRESSION_COLORS, PLOT_KINDS, PlotMode, PlotParams, QUADRATURES, UserExpression,
+};
+
+const EXPORT_ARG: &str = "--export";
+
+const DEFAULT_POINTS: u32 = 201;
+
+const USAGE: &str = "Usage: bessel_graph --export [--function NAME]... [--expression EXPR]... \
+[--order N] [--from X] [--to X] [--points N] [--output FILE]
+Functions: Integration, Infinite series, J, Y, I, K, j, y";
+
+fn parse_value<T: std::str::FromStr>(name: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or(format!("Missing value of {name}"))?;
+    value
+        .parse::<T>()
+        .map_err(|_| format!("Not a number: {name} {value}"))
+}
+
+/// Export values of the functions on the uniform grid by command-line arguments
+fn run_export(args: &[String]) -> Result<(), String> {
+    let mut enabled = vec![false; PLOT_KINDS.len()];
+    let mut expressions: Vec<UserExpression> = vec![];
+    let mut order = 0;
+    let (mut xmin, mut xmax): (f64, f64) = (0.0, 20.0);
+    let mut points = DEFAULT_POINTS;
+    let mut output: Option<String> = None;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            EXPORT_ARG => {}
+            "--function" => {
+                let name = it.next().ok_or("Missing value of --function")?;
+                let i = PLOT_KINDS
+                    .iter()
+                    .position(|k| k.name == name)
+                    .ok_or(format!("Unknown function: {name}"))?;
+                enabled[i] = true;
+            }
+            "--expression" => {
+                let text = it.next().ok_or("Missing value of --expression")?;
+                let expression =
+                    Expression::parse(text).map_err(|e| format!("Cannot parse {text}: {e}"))?;
+                expressions.push(UserExpression {
+                    name: text.clone(),
+                    expression,
+                    color: enums::Color::from_u32(
+                        EXPRESSION_COLORS[expressions.len() % EXPRESSION_COLORS.len()],
+                    ),
+                });
+            }
+            "--order" => order = parse_value(arg, it.next())?,
+            "--from" => xmin = parse_value(arg, it.next())?,
+            "--to" => xmax = parse_value(arg, it.next())?,
+            "--points" => points = parse_value(arg, it.next())?,
+            "--output" => output = Some(it.next().ok_or("Missing value of --output")?.clone()),
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+
+    if !enabled.contains(&true) && expressions.is_empty() {
+        return Err("No functions to export".to_string());
+    }
+    if !(xmin.is_finite() && xmax.is_finite()) {
+        return Err(format!("Range is not finite: --from {xmin} --to {xmax}"));
+    }
+    if xmax <= xmin {
+        return Err(format!("Empty range: --from {xmin} --to {xmax}"));
+    }
+    if !(0..=bessel::MAX_ORDER).contains(&order) {
+        return Err(format!(
+            "Order out of range 0..={}: --order {order}",
+            bessel::MAX_ORDER
+        ));
+    }
+    if points < 2 {
+        return Err(format!("Too few points: --points {points}"));
+    }
+
+    let params = PlotParams {
+        order,
+        quadrature: QUADRATURES[0],
+    };
+    let plots = plots::make_plots(params, PlotMode::Values, &enabled, &expressions);
+    let csv = export::grid_csv(&plots, xmin, xmax, points);
+    match output {
+        Some(path) => fs::write(&path, csv).map_err(|e| format!("Cannot write {path}: {e}")),
+        None => {
+            print!("{csv}");
+            Ok(())
+        }
+    }
+}
+
+/// Run command-line mode if it is requested. Returns exit code of the program
+pub fn run(args: &[String]) -> Option<i32> {
+    if !args.iter().any(|a| a == EXPORT_ARG) {
+        return None;
+    }
+
+    match run_export(args) {
+        Ok(()) => Some(0),
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("{USAGE}");
+            Some(1)
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use mechanics_common::plot_area::{PlotLines, Point};
+use mechanics_common::plot_widget::PlotFunctionInfo;
+
+// Names with separators are quoted
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+// Values outside of the function domain are left empty
+fn csv_value(v: f64) -> String {
+    if v.is_finite() {
+        v.to_string()
+    } else {
+        String::new()
+    }
+}
+
+// Points of the segments without the repeated ends of the adjacent segments
+fn polyline(lines: &PlotLines) -> Vec<Point> {
+    let mut points: Vec<Point> = vec![];
+    for (a, b) in lines {
+        if points.last().is_none_or(|p| p.x != a.x || p.y != a.y) {
+            points.push(*a);
+        }
+        points.push(*b);
+    }
+    points
+}
+
+/// Computed points of the curves as the pairs of columns of x and of the curve
+pub fn points_csv(plots: &[(PlotFunctionInfo, PlotLines)]) -> String {
+    let columns: Vec<Vec<Point>> = plots.iter().map(|p| polyline(&p.1)).collect();
+    let rows = columns.iter().map(Vec::len).max().unwrap_or(0);
+
+    let header: Vec<String> = plots
+        .iter()
+        .flat_map(|p| ["x".to_string(), csv_field(&p.0.name)])
+        .collect();
+    let mut csv = header.join(",") + "\n";
+
+    for i in 0..rows {
+        let row: Vec<String> = columns
+            .iter()
+            .flat_map(|c| match c.get(i) {
+                Some(p) => [csv_value(p.x), csv_value(p.y)],
+                None => [String::new(), String::new()],
+            })
+            .collect();
+        csv += &(row.join(",") + "\n");
+    }
+    csv
+}
+
+/// Values of the curves on the uniform grid of `count` points from `xmin` to `xmax`
+pub fn grid_csv(plots: &[PlotFunctionInfo], xmin: f64, xmax: f64, count: u32) -> String {
+    let count = count.max(2);
+
+    let mut header = vec!["x".to_string()];
+    header.extend(plots.iter().map(|p| csv_field(&p.name)));
+    let mut csv = header.join(",") + "\n";
+
+    for i in 0..count {
+        let x = xmin + (xmax - xmin) * f64::from(i) / f64::from(count - 1);
+        let mut row = vec![csv_value(x)];
+        row.extend(plots.iter().map(|p| csv_value((p.f)(x))));
+        csv += &(row.join(",") + "\n");
+    }
+    csv
+}
@@ -0,0 +1,79 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Generation of the jobs started by a single update of the plots.
+/// The generation is cancelled when the next one is started
+#[derive(Clone)]
+pub struct Generation {
+    id: u64,
+    current: Arc<AtomicU64>,
+}
+
+impl Generation {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.current.load(Ordering::Relaxed) != self.id
+    }
+}
+
+/// Fixed number of worker threads shared by the jobs of all plots
+pub struct JobPool {
+    sender: mpsc::Sender<Job>,
+    current: Arc<AtomicU64>,
+}
+
+impl JobPool {
+    pub fn new(threads: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for _ in 0..threads.max(1) {
+            let receiver = receiver.clone();
+            thread::spawn(move || {
+                loop {
+                    // Lock is released before the job is run
+                    let job = receiver.lock().map(|r| r.recv());
+                    match job {
+                        Ok(Ok(job)) => job(),
+                        // Pool is dropped
+                        _ => break,
+                    }
+                }
+            });
+        }
+
+        Self {
+            sender,
+            current: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Start the next generation of the jobs and cancel the previous one
+    pub fn next_generation(&self) -> Generation {
+        let id = self.current.fetch_add(1, Ordering::Relaxed) + 1;
+        Generation {
+            id,
+            current: self.current.clone(),
+        }
+    }
+
+    /// Queue the job of the generation. Jobs of the cancelled generations are skipped,
+    /// running jobs should check the generation to stop early
+    pub fn spawn<F>(&self, generation: &Generation, job: F)
+    where
+        F: FnOnce(&Generation) + Send + 'static,
+    {
+        let generation = generation.clone();
+        let _ = self.sender.send(Box::new(move || {
+            if !generation.is_cancelled() {
+                job(&generation);
+            }
+        }));
+    }
+}
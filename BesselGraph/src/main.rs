@@ -1,50 +1,348 @@
 #![allow(clippy::too_many_lines)]
+#![allow(clippy::collapsible_if)]
 
-mod bessel_func;
-mod plot_widget;
-use plot_widget::{Area, PlotFunctionInfo, PlotLines, PlotWidget};
+use bessel_functions::bessel;
+use bessel_functions::expression::Expression;
+use bessel_functions::zeros;
+use mechanics_common::plot_area::{Area, PlotLines, Point, Scale};
+use mechanics_common::plot_widget::{Marker, PlotFunctionInfo, PlotWidget};
+
+mod cli;
+mod export;
+
+mod jobs;
+use jobs::JobPool;
+
+mod plots;
+use plots::{
+    EXPRESSION_COLORS, PLOT_KINDS, PlotMode, PlotParams, QUADRATURES, UserExpression, make_plots,
+};
 
 mod res;
 use res::IconsAssets;
 
-use fltk::{app, button, enums, frame, group, input, prelude::*, window};
+use fltk::{app, button, dialog, enums, frame, group, input, menu, misc, prelude::*, window};
 
 use std::thread;
+use std::time::{Duration, Instant};
 
 const WIDTH: i32 = 700;
-const HEIGHT: i32 = 500;
+const HEIGHT: i32 = 750;
 const MARGIN: i32 = 10;
 
-const LINE_COUNT: i32 = 500;
+// Initial count of the segments of the plots before the subdivision
+const LINE_COUNT: i32 = 200;
+
+// Worker threads when the available parallelism is unknown
+const DEFAULT_THREADS: usize = 4;
+
+// Grid for the search of sign changes of the functions
+const ZERO_SAMPLES: u32 = 2000;
+
+// Points of the uniform grid of the exported values
+const EXPORT_POINTS: u32 = 201;
+
+// Plot areas of the values and of the errors in the logarithmic scale
+const VALUES_AREA: Area = Area {
+    xmin: 0.0,
+    xmax: 20.0,
+    ymin: -3.0,
+    ymax: 1.0,
+};
+const ERRORS_AREA: Area = Area {
+    xmin: 0.0,
+    xmax: 20.0,
+    ymin: 1e-16,
+    ymax: 1.0,
+};
+
+// Lower bound on the logarithmic axis relative to the upper one
+// when the lower bound is not positive
+const LOG_RANGE: f64 = 1e-3;
+
+// Row of the legend with the label, which is highlighted when the plot is calculated,
+// and the progress of the calculation
+struct LegendRow {
+    name: frame::Frame,
+    progress: misc::Progress,
+}
+
+fn make_legend(pack: &mut group::Flex, plots: &[PlotFunctionInfo]) -> Vec<LegendRow> {
+    let mut legend: Vec<LegendRow> = vec![];
+
+    pack.clear();
+    pack.begin();
+    for p in plots {
+        let mut row = group::Flex::default().row();
+
+        let mut legend_color = frame::Frame::default();
+        legend_color.set_frame(enums::FrameType::FlatBox);
+        legend_color.set_color(enums::Color::lighter(&p.color));
+        row.fixed(&legend_color, 45);
+
+        let mut legend_name = frame::Frame::default().with_label(&p.name);
+        legend_name.set_label_color(enums::Color::Dark3);
+        legend_name.set_align(enums::Align::Wrap | enums::Align::Inside);
+
+        let mut progress = misc::Progress::default();
+        progress.set_minimum(0.0);
+        progress.set_maximum(1.0);
+        progress.set_selection_color(enums::Color::lighter(&p.color));
+        row.fixed(&progress, 30);
+
+        legend.push(LegendRow {
+            name: legend_name,
+            progress,
+        });
+
+        row.end();
+        pack.fixed(&row, 35);
+    }
+    pack.end();
+    pack.layout();
+    pack.redraw();
+
+    legend
+}
+
+// Zeros of the plotted function within the plot area
+struct PlotZeros {
+    name: String,
+    color: enums::Color,
+    zeros: Vec<f64>,
+}
+
+// Zeros of the plots of the current generation. The search is started only when
+// the zeros are shown or saved, the results are kept until the plots are updated
+struct ZeroSearch {
+    plots: Vec<PlotFunctionInfo>,
+    area: Area,
+    started: bool,
+    pending: usize,
+    // Zeros are saved when the search is complete
+    save: bool,
+    found: Vec<PlotZeros>,
+}
+
+impl ZeroSearch {
+    fn new(plots: Vec<PlotFunctionInfo>, area: Area) -> Self {
+        Self {
+            plots,
+            area,
+            started: false,
+            pending: 0,
+            save: false,
+            found: vec![],
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.started && self.pending == 0
+    }
+
+    // Results are sent by the jobs of the generation with FinishZeros
+    fn start(&mut self, pool: &JobPool, generation: &jobs::Generation, tx: &app::Sender<Message>) {
+        if self.started {
+            return;
+        }
+        self.started = true;
+        self.pending = self.plots.len();
+
+        for p in self.plots.clone() {
+            let tx = tx.clone();
+            let area = self.area;
+            pool.spawn(generation, move |g| {
+                // Cancelled search skips the rest of the function values
+                let zeros = PlotZeros {
+                    name: p.name.clone(),
+                    color: p.color,
+                    zeros: zeros::find_zeros(
+                        |x| if g.is_cancelled() { f64::NAN } else { (p.f)(x) },
+                        area.xmin,
+                        area.xmax,
+                        ZERO_SAMPLES,
+                    ),
+                };
+                tx.send(Message::FinishZeros {
+                    generation: g.id(),
+                    zeros,
+                });
+            });
+        }
+    }
+}
+
+fn zero_markers(plot_zeros: &[PlotZeros]) -> Vec<Marker> {
+    plot_zeros
+        .iter()
+        .flat_map(|p| {
+            p.zeros.iter().map(|&x| Marker {
+                point: Point { x, y: 0.0 },
+                color: p.color.darker(),
+                label: format!("{x:.4}"),
+            })
+        })
+        .collect()
+}
+
+// Table of the zeros with the number of the zero of each function
+fn zeros_csv(plot_zeros: &[PlotZeros]) -> String {
+    let mut csv = String::from("function,n,x\n");
+    for p in plot_zeros {
+        for (i, x) in p.zeros.iter().enumerate() {
+            csv += &format!("{},{},{x}\n", p.name, i + 1);
+        }
+    }
+    csv
+}
+
+fn save_csv(csv: &str, preset_file: &str) {
+    let mut chooser = dialog::NativeFileChooser::new(dialog::FileDialogType::BrowseSaveFile);
+    chooser.set_option(dialog::FileDialogOptions::SaveAsConfirm);
+    chooser.set_filter("*.csv");
+    chooser.set_preset_file(preset_file);
+    chooser.show();
+
+    let path = chooser.filename();
+    if path.as_os_str().is_empty() {
+        return;
+    }
+
+    if let Err(e) = std::fs::write(&path, csv) {
+        dialog::alert_default(&format!("Cannot write {}: {e}", path.display()));
+    }
+}
+
+fn save_zeros(plot_zeros: &[PlotZeros]) {
+    if plot_zeros.iter().all(|p| p.zeros.is_empty()) {
+        dialog::alert_default("No zeros of the plotted functions are found");
+        return;
+    }
+
+    save_csv(&zeros_csv(plot_zeros), "bessel_zeros.csv");
+}
+
+// Visible curves are exported either by the calculated points or by the values
+// on the uniform grid over the plot area
+fn export_plots(plot_widget: &PlotWidget) {
+    let plots = plot_widget.visible_plots();
+    if plots.is_empty() {
+        dialog::alert_default("No plotted curves to export");
+        return;
+    }
+
+    let csv = match dialog::choice2_default(
+        "Export the calculated points of the curves or the values on the uniform grid?",
+        "Cancel",
+        "Points",
+        "Grid",
+    ) {
+        Some(1) => export::points_csv(&plots),
+        Some(2) => {
+            let area = plot_widget.get_area();
+            let functions: Vec<PlotFunctionInfo> = plots.into_iter().map(|p| p.0).collect();
+            export::grid_csv(&functions, area.xmin, area.xmax, EXPORT_POINTS)
+        }
+        _ => return,
+    };
+
+    save_csv(&csv, "bessel_plots.csv");
+}
+
+// Bound of the plot area with the significant digits down to 1e-4 of the width
+// of the axis and insignificant zeros removed, small and large bounds are shown
+// in exponential notation
+fn format_bound(v: f64, width: f64) -> String {
+    let digits = (v.abs() / width).log10().ceil().clamp(0.0, 12.0) as usize + 4;
+    let v: f64 = format!("{v:.digits$e}").parse().unwrap_or(v);
+    if v == 0.0 {
+        "0".to_string()
+    } else if (1e-3..1e6).contains(&v.abs()) {
+        format!("{v}")
+    } else {
+        format!("{v:e}")
+    }
+}
+
+// Bounds of the plot area entered by the user, the functions are plotted for x >= 0
+fn read_area(
+    min_x: &input::FloatInput,
+    max_x: &input::FloatInput,
+    min_y: &input::FloatInput,
+    max_y: &input::FloatInput,
+) -> Result<Area, String> {
+    let read = |inp: &input::FloatInput| {
+        let text = inp.value();
+        text.trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| format!("{}: '{text}' is not a number", inp.label()))
+    };
+
+    let area = Area {
+        xmin: read(min_x)?.max(0.0),
+        xmax: read(max_x)?,
+        ymin: read(min_y)?,
+        ymax: read(max_y)?,
+    };
+    if area.xmax <= area.xmin {
+        return Err("x max must be greater than x min and 0".to_string());
+    }
+    if area.ymax <= area.ymin {
+        return Err("y max must be greater than y min".to_string());
+    }
+    Ok(area)
+}
+
+// Bounds of the logarithmic axis are made positive
+fn log_bounds(min: f64, max: f64) -> (f64, f64) {
+    if max <= 0.0 {
+        (LOG_RANGE, 1.0)
+    } else if min <= 0.0 {
+        (max * LOG_RANGE, max)
+    } else {
+        (min, max)
+    }
+}
 
 enum Message {
+    AddExpression,
+    ClearExpressions,
+    UpdateMode,
+    ViewArea(Area),
+    ResetView,
+    // Bounds are entered by the user
+    ReadArea,
+    // Plots are updated in the current area
     UpdateArea,
     UpdatePlots(Vec<PlotFunctionInfo>),
-    FinishCalculation((PlotFunctionInfo, PlotLines)),
+    // Results of the jobs with the generation of the plots and the index in the legend
+    Progress {
+        generation: u64,
+        index: usize,
+        fraction: f64,
+    },
+    FinishCalculation {
+        generation: u64,
+        index: usize,
+        plot: (PlotFunctionInfo, PlotLines),
+        elapsed: Duration,
+    },
+    FinishZeros {
+        generation: u64,
+        zeros: PlotZeros,
+    },
+    ShowZeros,
+    SaveZeros,
+    ExportPlots,
 }
 
 fn main() {
-    // Plot parameters
-    const DEFAULT_AREA: Area = Area {
-        xmin: 0.0,
-        xmax: 20.0,
-        ymin: -3.0,
-        ymax: 1.0,
-    };
-
-    let plots: Vec<PlotFunctionInfo> = [
-        PlotFunctionInfo {
-            f: bessel_func::y0_1,
-            color: enums::Color::from_u32(0x00_a0_00_00),
-            name: "Integration".to_string(),
-        },
-        PlotFunctionInfo {
-            f: bessel_func::y0_2,
-            color: enums::Color::from_u32(0x00_99_cc_ff),
-            name: "Infinite series".to_string(),
-        },
-    ]
-    .to_vec();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(code) = cli::run(&args) {
+        std::process::exit(code);
+    }
 
     // App and main window
     let a = app::App::default();
@@ -144,14 +442,44 @@ fn main() {
         bounds_frame_flex.fixed(&spacer, 15);
     }
 
+    let mut check_log_x;
+    let mut check_log_y;
+    {
+        let row = group::Flex::default_fill().row();
+
+        check_log_x = button::CheckButton::default().with_label("log x");
+        check_log_x.set_tooltip("Logarithmic scale of the x axis");
+
+        check_log_y = button::CheckButton::default().with_label("log y");
+        check_log_y.set_tooltip("Logarithmic scale of the y axis, errors are always plotted in it");
+
+        row.end();
+
+        bounds_frame_flex.fixed(&row, 25);
+    }
+
+    {
+        let spacer = frame::Frame::default();
+        bounds_frame_flex.fixed(&spacer, 15);
+    }
+
     let mut btn_redraw;
+    let mut btn_reset;
     {
         let mut row = group::Flex::default_fill().row();
 
         frame::Frame::default();
 
         btn_redraw = button::Button::default().with_label("Update");
-        row.fixed(&btn_redraw, 90);
+        row.fixed(&btn_redraw, 60);
+
+        btn_reset = button::Button::default().with_label("Reset");
+        btn_reset.set_tooltip(
+            "Reset plot bounds. Mouse wheel zooms the plot, drag moves it, \
+             right button drag (or Shift + drag) zooms into the selection, \
+             double click resets the bounds",
+        );
+        row.fixed(&btn_reset, 60);
 
         frame::Frame::default();
 
@@ -167,14 +495,193 @@ fn main() {
 
     {
         let spacer = frame::Frame::default();
-        controls_column.fixed(&spacer, 10);
+        controls_column.fixed(&spacer, 25);
+    }
+
+    // Functions to plot
+    let mut functions_frame = group::Group::default()
+        .with_pos(0, 0)
+        .with_size(100, 100)
+        .with_label("Functions");
+    functions_frame.set_frame(enums::FrameType::BorderFrame);
+    functions_frame.set_color(enums::Color::Black);
+
+    let mut functions_flex = group::Flex::default_fill().column();
+    functions_flex.set_margin(MARGIN);
+
+    let mut spin_order;
+    {
+        let mut row = group::Flex::default_fill().row();
+
+        frame::Frame::default();
+
+        spin_order = misc::Spinner::default().with_label("order n ");
+        spin_order.set_range(0.0, f64::from(bessel::MAX_ORDER));
+        spin_order.set_step(1.0);
+        spin_order.set_value(0.0);
+        spin_order.set_tooltip("Order of the functions");
+        row.fixed(&spin_order, 60);
+
+        row.end();
+
+        functions_flex.fixed(&row, 25);
+    }
+
+    let mut mode_choice;
+    {
+        let mut row = group::Flex::default_fill().row();
+
+        frame::Frame::default();
+
+        mode_choice = menu::Choice::default().with_label("plot ");
+        for m in PlotMode::ALL {
+            mode_choice.add_choice(m.name());
+        }
+        mode_choice.set_value(0);
+        mode_choice.set_tooltip("Plot values or errors against the reference functions");
+        row.fixed(&mode_choice, 100);
+
+        row.end();
+
+        functions_flex.fixed(&row, 25);
+    }
+
+    let mut quadrature_choice;
+    {
+        let mut row = group::Flex::default_fill().row();
+
+        frame::Frame::default();
+
+        quadrature_choice = menu::Choice::default().with_label("rule ");
+        for q in QUADRATURES {
+            quadrature_choice.add_choice(&q.to_string());
+        }
+        quadrature_choice.set_value(0);
+        quadrature_choice.set_tooltip("Quadrature rule of Y0(x) calculated by integration");
+        row.fixed(&quadrature_choice, 140);
+
+        row.end();
+
+        functions_flex.fixed(&row, 25);
+    }
+
+    let mut checks: Vec<button::CheckButton> = vec![];
+    for pair in PLOT_KINDS.chunks(2) {
+        let row = group::Flex::default_fill().row();
+
+        for k in pair {
+            let label = if k.has_order {
+                format!("{}n", k.name)
+            } else {
+                k.name.to_string()
+            };
+            let mut check = button::CheckButton::default().with_label(&label);
+            check.set_tooltip(k.tooltip);
+            check.set_checked(!k.has_order);
+            checks.push(check);
+        }
+
+        row.end();
+
+        functions_flex.fixed(&row, 25);
+    }
+
+    let mut check_zeros;
+    let mut btn_save_zeros;
+    {
+        let row = group::Flex::default_fill().row();
+
+        check_zeros = button::CheckButton::default().with_label("Zeros");
+        check_zeros.set_tooltip("Mark zeros of the functions within the plot bounds");
+
+        btn_save_zeros = button::Button::default().with_label("Save...");
+        btn_save_zeros.set_tooltip("Export zeros of the functions as CSV");
+
+        row.end();
+
+        functions_flex.fixed(&row, 25);
+    }
+
+    let mut btn_export;
+    {
+        let mut row = group::Flex::default_fill().row();
+
+        frame::Frame::default();
+
+        btn_export = button::Button::default().with_label("Export...");
+        btn_export.set_tooltip("Export the visible curves as CSV");
+        row.fixed(&btn_export, 90);
+
+        row.end();
+
+        functions_flex.fixed(&row, 25);
+    }
+
+    functions_flex.end();
+
+    functions_frame.end();
+    controls_column.fixed(&functions_frame, 25 * 9 + MARGIN * 3);
+
+    {
+        let spacer = frame::Frame::default();
+        controls_column.fixed(&spacer, 25);
+    }
+
+    // Expressions entered by the user
+    let mut expressions_frame = group::Group::default()
+        .with_pos(0, 0)
+        .with_size(100, 100)
+        .with_label("Expressions");
+    expressions_frame.set_frame(enums::FrameType::BorderFrame);
+    expressions_frame.set_color(enums::Color::Black);
+
+    let mut expressions_flex = group::Flex::default_fill().column();
+    expressions_flex.set_margin(MARGIN);
+
+    let mut in_expression = input::Input::default();
+    in_expression.set_tooltip(
+        "Expression of x, e.g. x*J1(x) - 2*Y0(x). Functions of order n: Jn, Yn, In, Kn, jn, yn \
+         or J(n, x), Y0 by integration and by series: y0_1, y0_2, elementary functions: \
+         sin, cos, tan, asin, acos, atan, sinh, cosh, tanh, exp, ln, log10, sqrt, abs",
+    );
+    expressions_flex.fixed(&in_expression, 25);
+
+    let mut in_expression_name;
+    let mut btn_add_expression;
+    let mut btn_clear_expressions;
+    {
+        let mut row = group::Flex::default_fill().row();
+
+        in_expression_name = input::Input::default();
+        in_expression_name.set_tooltip("Name of the expression in the legend");
+
+        btn_add_expression = button::Button::default().with_label("Add");
+        row.fixed(&btn_add_expression, 40);
+
+        btn_clear_expressions = button::Button::default().with_label("Clear");
+        btn_clear_expressions.set_tooltip("Remove all expressions");
+        row.fixed(&btn_clear_expressions, 45);
+
+        row.end();
+
+        expressions_flex.fixed(&row, 25);
+    }
+
+    expressions_flex.end();
+
+    expressions_frame.end();
+    controls_column.fixed(&expressions_frame, 25 * 2 + MARGIN * 3);
+
+    {
+        let spacer = frame::Frame::default();
+        controls_column.fixed(&spacer, 25);
     }
 
-    // Legend
+    // Progress and time of the calculation of the plots
     let mut legend_frame = group::Group::default()
         .with_pos(0, 0)
         .with_size(100, 100)
-        .with_label("Legend");
+        .with_label("Calculation");
     legend_frame.set_frame(enums::FrameType::BorderFrame);
     legend_frame.set_color(enums::Color::Black);
 
@@ -209,133 +716,415 @@ fn main() {
 
     let (tx, rx) = app::channel::<Message>();
 
+    let pool = JobPool::new(thread::available_parallelism().map_or(DEFAULT_THREADS, usize::from));
+    let mut generation = pool.next_generation();
+
     // Callbacks
     in_max_x.set_callback({
         let tx = tx.clone();
         move |_b| {
-            tx.send(Message::UpdateArea);
+            tx.send(Message::ReadArea);
         }
     });
     in_min_x.set_callback({
         let tx = tx.clone();
         move |_b| {
-            tx.send(Message::UpdateArea);
+            tx.send(Message::ReadArea);
         }
     });
     in_max_y.set_callback({
         let tx = tx.clone();
         move |_b| {
-            tx.send(Message::UpdateArea);
+            tx.send(Message::ReadArea);
         }
     });
     in_min_y.set_callback({
         let tx = tx.clone();
         move |_b| {
-            tx.send(Message::UpdateArea);
+            tx.send(Message::ReadArea);
         }
     });
 
     btn_redraw.set_callback({
         let tx = tx.clone();
         move |_b| {
+            tx.send(Message::ReadArea);
+        }
+    });
+
+    check_log_x.set_callback({
+        let tx = tx.clone();
+        move |_c| {
+            tx.send(Message::UpdateArea);
+        }
+    });
+    check_log_y.set_callback({
+        let tx = tx.clone();
+        move |_c| {
             tx.send(Message::UpdateArea);
         }
     });
 
-    // Initial setup
-    {
-        let area = DEFAULT_AREA;
+    btn_reset.set_callback({
+        let tx = tx.clone();
+        move |_b| {
+            tx.send(Message::ResetView);
+        }
+    });
 
-        plot_widget.set_area(area);
+    plot_widget.set_crosshair(true);
+    plot_widget.enable_navigation({
+        let tx = tx.clone();
+        move |area| {
+            tx.send(Message::ViewArea(area));
+        }
+    });
 
-        in_max_x.set_value(format!("{:.1}", area.xmax).as_str());
-        in_min_x.set_value(format!("{:.1}", area.xmin).as_str());
-        in_max_y.set_value(format!("{:.1}", area.ymax).as_str());
-        in_min_y.set_value(format!("{:.1}", area.ymin).as_str());
-    }
+    mode_choice.set_callback({
+        let tx = tx.clone();
+        move |_c| {
+            tx.send(Message::UpdateMode);
+        }
+    });
 
-    // Setup plot legend
-    let mut legend: Vec<frame::Frame> = vec![];
-    {
-        pack.begin();
-        for p in &plots {
-            let mut row = group::Flex::default().row();
+    btn_export.set_callback({
+        let tx = tx.clone();
+        move |_b| {
+            tx.send(Message::ExportPlots);
+        }
+    });
 
-            let mut legend_color = frame::Frame::default();
-            legend_color.set_frame(enums::FrameType::FlatBox);
-            legend_color.set_color(enums::Color::lighter(&p.color));
-            row.fixed(&legend_color, 45);
+    check_zeros.set_callback({
+        let tx = tx.clone();
+        move |_c| {
+            tx.send(Message::ShowZeros);
+        }
+    });
 
-            let mut legend_name = frame::Frame::default().with_label(&p.name);
-            legend_name.set_label_color(enums::Color::Dark3);
-            legend.push(legend_name);
+    btn_save_zeros.set_callback({
+        let tx = tx.clone();
+        move |_b| {
+            tx.send(Message::SaveZeros);
+        }
+    });
 
-            row.end();
-            pack.fixed(&row, 30);
+    in_expression.set_trigger(enums::CallbackTrigger::EnterKey);
+    in_expression.set_callback({
+        let tx = tx.clone();
+        move |_i| {
+            tx.send(Message::AddExpression);
+        }
+    });
+    btn_add_expression.set_callback({
+        let tx = tx.clone();
+        move |_b| {
+            tx.send(Message::AddExpression);
         }
-        pack.end();
+    });
+    btn_clear_expressions.set_callback({
+        let tx = tx.clone();
+        move |_b| {
+            tx.send(Message::ClearExpressions);
+        }
+    });
+
+    quadrature_choice.set_callback({
+        let tx = tx.clone();
+        move |_c| {
+            tx.send(Message::UpdateArea);
+        }
+    });
+
+    spin_order.set_callback({
+        let tx = tx.clone();
+        move |_s| {
+            tx.send(Message::UpdateArea);
+        }
+    });
+    for check in &mut checks {
+        check.set_callback({
+            let tx = tx.clone();
+            move |_c| {
+                tx.send(Message::UpdateArea);
+            }
+        });
+    }
+
+    // Initial setup
+    {
+        let area = VALUES_AREA;
+
+        plot_widget.set_area(area);
+        plot_widget.set_home_area(area);
+
+        let (width, height) = (area.xmax - area.xmin, area.ymax - area.ymin);
+        in_max_x.set_value(&format_bound(area.xmax, width));
+        in_min_x.set_value(&format_bound(area.xmin, width));
+        in_max_y.set_value(&format_bound(area.ymax, height));
+        in_min_y.set_value(&format_bound(area.ymin, height));
     }
 
+    let mut legend: Vec<LegendRow> = vec![];
+    let mut zero_search = ZeroSearch::new(vec![], VALUES_AREA);
+    let mut expressions: Vec<UserExpression> = vec![];
+
     wind.show();
 
     // Calculate functions
-    tx.send(Message::UpdatePlots(plots.clone()));
+    tx.send(Message::UpdatePlots(make_plots(
+        PlotParams {
+            order: spin_order.value() as i32,
+            quadrature: QUADRATURES[quadrature_choice.value() as usize],
+        },
+        PlotMode::ALL[mode_choice.value() as usize],
+        &checks
+            .iter()
+            .map(button::CheckButton::is_checked)
+            .collect::<Vec<_>>(),
+        &expressions,
+    )));
 
     while a.wait() {
         if let Some(msg) = rx.recv() {
             match msg {
+                Message::AddExpression => {
+                    let text = in_expression.value();
+                    match Expression::parse(&text) {
+                        Ok(expression) => {
+                            let name = in_expression_name.value();
+                            expressions.push(UserExpression {
+                                name: if name.trim().is_empty() {
+                                    text.trim().to_string()
+                                } else {
+                                    name.trim().to_string()
+                                },
+                                expression,
+                                color: enums::Color::from_u32(
+                                    EXPRESSION_COLORS[expressions.len() % EXPRESSION_COLORS.len()],
+                                ),
+                            });
+                            in_expression.set_value("");
+                            in_expression_name.set_value("");
+                            tx.send(Message::UpdateArea);
+                        }
+                        Err(e) => dialog::alert_default(&format!("Cannot parse {text}: {e}")),
+                    }
+                }
+                Message::ClearExpressions => {
+                    expressions.clear();
+                    tx.send(Message::UpdateArea);
+                }
+                Message::UpdateMode => {
+                    // Errors are plotted in the logarithmic scale, zeros are found
+                    // only for the values
+                    let mode = PlotMode::ALL[mode_choice.value() as usize];
+                    let (area, y_label) = match mode {
+                        PlotMode::Values => (VALUES_AREA, ""),
+                        _ => (ERRORS_AREA, "error"),
+                    };
+                    if mode == PlotMode::Values {
+                        check_zeros.activate();
+                        btn_save_zeros.activate();
+                        check_log_y.set_checked(false);
+                        check_log_y.activate();
+                    } else {
+                        check_zeros.deactivate();
+                        btn_save_zeros.deactivate();
+                        check_log_y.set_checked(true);
+                        check_log_y.deactivate();
+                    }
+                    let view = Area {
+                        ymin: area.ymin,
+                        ymax: area.ymax,
+                        ..plot_widget.get_area()
+                    };
+                    plot_widget.set_area(view);
+                    plot_widget.set_axis_labels("", y_label);
+                    plot_widget.set_home_area(area);
+
+                    tx.send(Message::UpdateArea);
+                }
+                Message::ViewArea(area) => {
+                    // Area chosen with the mouse
+                    plot_widget.set_area(area);
+                    tx.send(Message::UpdateArea);
+                }
+                Message::ResetView => plot_widget.reset_view(),
+                Message::ReadArea => match read_area(&in_min_x, &in_max_x, &in_min_y, &in_max_y) {
+                    Ok(area) => {
+                        plot_widget.set_area(area);
+                        tx.send(Message::UpdateArea);
+                    }
+                    Err(e) => dialog::alert_default(&format!("Invalid plot bounds. {e}")),
+                },
                 Message::UpdateArea => {
-                    let max_x: f64 = in_max_x.value().parse::<f64>().expect("Not a number!");
-                    let min_x: f64 = in_min_x.value().parse::<f64>().expect("Not a number!");
-                    let max_y: f64 = in_max_y.value().parse::<f64>().expect("Not a number!");
-                    let min_y: f64 = in_min_y.value().parse::<f64>().expect("Not a number!");
-
-                    plot_widget.set_area(plot_widget::Area {
-                        xmin: min_x.max(0.0),
-                        xmax: max_x,
-                        ymin: min_y,
-                        ymax: max_y,
-                    });
-
-                    tx.send(Message::UpdatePlots(plots.clone()));
+                    // Inputs only show the bounds of the area
+                    let mut area = plot_widget.get_area();
+                    area.xmin = area.xmin.max(0.0);
+                    let scale = |log: bool| if log { Scale::Log } else { Scale::Linear };
+                    let scales = (
+                        scale(check_log_x.is_checked()),
+                        scale(check_log_y.is_checked()),
+                    );
+                    if scales.0 == Scale::Log {
+                        (area.xmin, area.xmax) = log_bounds(area.xmin, area.xmax);
+                    }
+                    if scales.1 == Scale::Log {
+                        (area.ymin, area.ymax) = log_bounds(area.ymin, area.ymax);
+                    }
+                    let (width, height) = (area.xmax - area.xmin, area.ymax - area.ymin);
+                    in_min_x.set_value(&format_bound(area.xmin, width));
+                    in_max_x.set_value(&format_bound(area.xmax, width));
+                    in_min_y.set_value(&format_bound(area.ymin, height));
+                    in_max_y.set_value(&format_bound(area.ymax, height));
+
+                    plot_widget.set_scales(scales.0, scales.1);
+                    plot_widget.set_area(area);
+
+                    tx.send(Message::UpdatePlots(make_plots(
+                        PlotParams {
+                            order: spin_order.value() as i32,
+                            quadrature: QUADRATURES[quadrature_choice.value() as usize],
+                        },
+                        PlotMode::ALL[mode_choice.value() as usize],
+                        &checks
+                            .iter()
+                            .map(button::CheckButton::is_checked)
+                            .collect::<Vec<_>>(),
+                        &expressions,
+                    )));
                 }
                 Message::UpdatePlots(plots) => {
                     plot_widget.clear_plots();
+                    plot_widget.set_markers(vec![]);
                     plot_widget.redraw();
 
-                    for w in &mut legend {
-                        w.set_label_color(enums::Color::Dark3);
-                        w.redraw_label();
-                    }
+                    // Setup plot legend
+                    legend = make_legend(&mut pack, &plots);
+
+                    // Jobs of the previous update are cancelled
+                    generation = pool.next_generation();
 
                     let area = plot_widget.get_area();
-                    let _ = plots
-                        .into_iter()
-                        .map(|p| {
-                            thread::spawn({
-                                let tx = tx.clone();
-                                move || {
-                                    println!("Start calculation of plot {}", p.name);
-
-                                    // Calculate plot points
-                                    let lines_vec = p.calc_points(LINE_COUNT, &area);
-                                    tx.send(Message::FinishCalculation((p, lines_vec)));
-                                }
+                    let transform = plot_widget.transform();
+                    // Zeros are found only for the values
+                    let values = PlotMode::ALL[mode_choice.value() as usize] == PlotMode::Values;
+                    zero_search =
+                        ZeroSearch::new(if values { plots.clone() } else { vec![] }, area);
+                    for (index, p) in plots.into_iter().enumerate() {
+                        let tx = tx.clone();
+                        pool.spawn(&generation, move |g| {
+                            println!("Start calculation of plot {}", p.name);
+
+                            // Calculate plot points, evaluation time is shown for
+                            // the comparison of the methods
+                            let start = Instant::now();
+                            let mut percent = 0;
+                            let Some(lines_vec) =
+                                p.calc_points_with_progress(LINE_COUNT, &transform, |fraction| {
+                                    // Progress is reported by whole percents
+                                    let new_percent = (fraction * 100.0) as i32;
+                                    if new_percent > percent {
+                                        percent = new_percent;
+                                        tx.send(Message::Progress {
+                                            generation: g.id(),
+                                            index,
+                                            fraction,
+                                        });
+                                    }
+                                    !g.is_cancelled()
+                                })
+                            else {
+                                println!("Cancelled calculation of plot {}", p.name);
+                                return;
+                            };
+                            let elapsed = start.elapsed();
+
+                            tx.send(Message::FinishCalculation {
+                                generation: g.id(),
+                                index,
+                                plot: (p, lines_vec),
+                                elapsed,
                             });
-                        })
-                        .collect::<Vec<_>>();
-                }
-                Message::FinishCalculation(p) => {
-                    println!("Calculated plot {} with {} lines", &p.0.name, p.1.len());
+                        });
+                    }
 
-                    if let Some(w) = legend.iter_mut().find(|w| w.label() == p.0.name) {
-                        w.set_label_color(p.0.color.darker());
-                        w.redraw_label();
+                    // Search is queued after the plots
+                    if check_zeros.is_checked() {
+                        zero_search.start(&pool, &generation, &tx);
+                    }
+                }
+                // Results of the cancelled jobs are ignored
+                Message::Progress {
+                    generation: id,
+                    index,
+                    fraction,
+                } if id == generation.id() => {
+                    if let Some(row) = legend.get_mut(index) {
+                        row.progress.set_value(fraction);
+                    }
+                }
+                Message::FinishCalculation {
+                    generation: id,
+                    index,
+                    plot: p,
+                    elapsed,
+                } if id == generation.id() => {
+                    println!(
+                        "Calculated plot {} with {} lines in {elapsed:?}",
+                        &p.0.name,
+                        p.1.len()
+                    );
+
+                    if let Some(row) = legend.get_mut(index) {
+                        row.name.set_label(&format!(
+                            "{} {:.1} ms",
+                            p.0.name,
+                            elapsed.as_secs_f64() * 1e3
+                        ));
+                        row.name.set_label_color(p.0.color.darker());
+                        row.name.redraw_label();
+                        row.progress.hide();
                     }
 
                     plot_widget.add_plot(p);
                     plot_widget.redraw();
                 }
+                Message::FinishZeros {
+                    generation: id,
+                    zeros: z,
+                } if id == generation.id() => {
+                    println!("Found {} zeros of plot {}", z.zeros.len(), z.name);
+
+                    zero_search.found.push(z);
+                    zero_search.pending -= 1;
+                    if zero_search.is_complete() && zero_search.save {
+                        zero_search.save = false;
+                        save_zeros(&zero_search.found);
+                    }
+                    tx.send(Message::ShowZeros);
+                }
+                Message::Progress { .. }
+                | Message::FinishCalculation { .. }
+                | Message::FinishZeros { .. } => {}
+                Message::ShowZeros => {
+                    if check_zeros.is_checked() {
+                        zero_search.start(&pool, &generation, &tx);
+                        plot_widget.set_markers(zero_markers(&zero_search.found));
+                    } else {
+                        plot_widget.set_markers(vec![]);
+                    }
+                    plot_widget.redraw();
+                }
+                Message::SaveZeros => {
+                    zero_search.start(&pool, &generation, &tx);
+                    if zero_search.is_complete() {
+                        save_zeros(&zero_search.found);
+                    } else {
+                        zero_search.save = true;
+                    }
+                }
+                Message::ExportPlots => export_plots(&plot_widget),
             }
         }
     }
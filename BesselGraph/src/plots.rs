@@ -0,0 +1,248 @@
+use bessel_functions::bessel;
+use bessel_functions::bessel_func::{self, Quadrature};
+use bessel_functions::expression::Expression;
+use mechanics_common::plot_widget::{PlotFunction, PlotFunctionInfo};
+
+use fltk::enums;
+
+use std::sync::Arc;
+
+// Quadrature rules of the functions calculated by integration, the first one is
+// the default rule of y0_1 that the faster rules are compared with
+pub const QUADRATURES: [Quadrature; 5] = [
+    Quadrature::Simpson(10_000),
+    Quadrature::Simpson(100),
+    Quadrature::GaussLegendre(16),
+    Quadrature::GaussLegendre(64),
+    Quadrature::AdaptiveSimpson(1e-10),
+];
+
+// Errors below this value are shown at the bottom of the log scale
+const MIN_ERROR: f64 = 1e-17;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum PlotMode {
+    Values,
+    AbsoluteError,
+    RelativeError,
+}
+
+impl PlotMode {
+    pub const ALL: [PlotMode; 3] = [
+        PlotMode::Values,
+        PlotMode::AbsoluteError,
+        PlotMode::RelativeError,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PlotMode::Values => "Values",
+            PlotMode::AbsoluteError => "Abs. error",
+            PlotMode::RelativeError => "Rel. error",
+        }
+    }
+}
+
+// Colors of the expressions entered by the user in the order of addition
+pub const EXPRESSION_COLORS: [u32; 6] = [
+    0x00_ff_40_80,
+    0x00_40_80_40,
+    0x00_80_40_00,
+    0x00_40_40_ff,
+    0x00_ff_80_00,
+    0x00_60_60_60,
+];
+
+// Expression entered by the user that is plotted with the functions
+pub struct UserExpression {
+    pub name: String,
+    pub expression: Expression,
+    pub color: enums::Color,
+}
+
+// Parameters of the plotted functions chosen by the user
+#[derive(Copy, Clone)]
+pub struct PlotParams {
+    pub order: i32,
+    pub quadrature: Quadrature,
+}
+
+pub type KindFunction = fn(&PlotParams, f64) -> f64;
+
+// Function that can be plotted, functions of order n are named by the order,
+// functions calculated by integration are named by the quadrature rule.
+// Errors are calculated against the reference function
+pub struct PlotKind {
+    pub name: &'static str,
+    pub color: u32,
+    pub f: KindFunction,
+    pub reference: Option<KindFunction>,
+    pub has_order: bool,
+    pub has_quadrature: bool,
+    pub tooltip: &'static str,
+}
+
+pub const PLOT_KINDS: [PlotKind; 8] = [
+    PlotKind {
+        name: "Integration",
+        color: 0x00_a0_00_00,
+        f: |p, x| bessel_func::y0_integral(x, p.quadrature),
+        reference: Some(|_, x| bessel::y0(x)),
+        has_order: false,
+        has_quadrature: true,
+        tooltip: "Y0(x) calculated by integration",
+    },
+    PlotKind {
+        name: "Infinite series",
+        color: 0x00_99_cc_ff,
+        f: |_, x| bessel_func::y0_2(x),
+        reference: Some(|_, x| bessel::y0(x)),
+        has_order: false,
+        has_quadrature: false,
+        tooltip: "Y0(x) calculated by infinite series",
+    },
+    PlotKind {
+        name: "J",
+        color: 0x00_00_80_00,
+        f: |p, x| bessel::jn(p.order, x),
+        reference: None,
+        has_order: true,
+        has_quadrature: false,
+        tooltip: "Bessel function of the first kind",
+    },
+    PlotKind {
+        name: "Y",
+        color: 0x00_00_40_c0,
+        f: |p, x| bessel::yn(p.order, x),
+        reference: None,
+        has_order: true,
+        has_quadrature: false,
+        tooltip: "Bessel function of the second kind",
+    },
+    PlotKind {
+        name: "I",
+        color: 0x00_c0_60_00,
+        f: |p, x| bessel::i_n(p.order, x),
+        reference: None,
+        has_order: true,
+        has_quadrature: false,
+        tooltip: "Modified Bessel function of the first kind",
+    },
+    PlotKind {
+        name: "K",
+        color: 0x00_80_00_80,
+        f: |p, x| bessel::k_n(p.order, x),
+        reference: None,
+        has_order: true,
+        has_quadrature: false,
+        tooltip: "Modified Bessel function of the second kind",
+    },
+    PlotKind {
+        name: "j",
+        color: 0x00_00_a0_a0,
+        f: |p, x| bessel::sph_jn(p.order, x),
+        reference: None,
+        has_order: true,
+        has_quadrature: false,
+        tooltip: "Spherical Bessel function of the first kind",
+    },
+    PlotKind {
+        name: "y",
+        color: 0x00_a0_a0_00,
+        f: |p, x| bessel::sph_yn(p.order, x),
+        reference: None,
+        has_order: true,
+        has_quadrature: false,
+        tooltip: "Spherical Bessel function of the second kind",
+    },
+];
+
+// Plots of the enabled functions with the parameters and of the user expressions.
+// In the error modes the error is plotted for the functions with reference.
+// Errors are limited from below to be drawn in the logarithmic scale, NaN errors
+// outside the domain of the functions are left as gaps
+pub fn make_plots(
+    params: PlotParams,
+    mode: PlotMode,
+    enabled: &[bool],
+    expressions: &[UserExpression],
+) -> Vec<PlotFunctionInfo> {
+    let mut plots: Vec<PlotFunctionInfo> = PLOT_KINDS
+        .iter()
+        .zip(enabled)
+        .filter(|(k, e)| **e && (mode == PlotMode::Values || k.reference.is_some()))
+        .map(|(k, _)| {
+            let f = k.f;
+            let name = if k.has_order {
+                format!("{}{}(x)", k.name, params.order)
+            } else if k.has_quadrature {
+                format!("{} ({})", k.name, params.quadrature)
+            } else {
+                k.name.to_string()
+            };
+
+            let plot: PlotFunction = match (mode, k.reference) {
+                (PlotMode::Values, _) | (_, None) => Arc::new(move |x| f(&params, x)),
+                (_, Some(reference)) => Arc::new(move |x| {
+                    let r = reference(&params, x);
+                    let error = (f(&params, x) - r).abs();
+                    let error = if mode == PlotMode::RelativeError {
+                        error / r.abs()
+                    } else {
+                        error
+                    };
+                    if error.is_nan() {
+                        f64::NAN
+                    } else {
+                        error.max(MIN_ERROR)
+                    }
+                }),
+            };
+
+            PlotFunctionInfo {
+                f: plot,
+                color: enums::Color::from_u32(k.color),
+                name: if mode == PlotMode::Values {
+                    name
+                } else {
+                    format!("{name} {}", mode.name().to_lowercase())
+                },
+            }
+        })
+        .collect();
+
+    if mode == PlotMode::Values {
+        plots.extend(expressions.iter().map(|e| {
+            let expression = e.expression.clone();
+            PlotFunctionInfo {
+                f: Arc::new(move |x| expression.eval(x)),
+                color: e.color,
+                name: e.name.clone(),
+            }
+        }));
+    }
+
+    plots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_is_nan_outside_domain() {
+        let params = PlotParams {
+            order: 0,
+            quadrature: QUADRATURES[0],
+        };
+        let enabled: Vec<bool> = PLOT_KINDS.iter().map(|k| k.reference.is_some()).collect();
+
+        for mode in [PlotMode::AbsoluteError, PlotMode::RelativeError] {
+            for plot in make_plots(params, mode, &enabled, &[]) {
+                // Y0 is not defined at x = 0
+                assert!((plot.f)(0.0).is_nan(), "{} at 0 is not NaN", plot.name);
+                assert!((plot.f)(1.0) >= MIN_ERROR, "{} at 1", plot.name);
+            }
+        }
+    }
+}
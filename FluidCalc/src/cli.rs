@@ -0,0 +1,66 @@
+use std::fs;
+
+use gas_dynamics::flow_table::{self, TableFormat, TableParams};
+
+const TABLE_ARG: &str = "--table";
+
+const USAGE: &str = "Usage: fluid_calc --table [--format csv|md|tex] [--from LAMBDA] \
+[--to LAMBDA] [--step STEP] [--k K] [--mach] [--output FILE]";
+
+fn parse_value(name: &str, value: Option<&String>) -> Result<f64, String> {
+    let value = value.ok_or(format!("Missing value of {name}"))?;
+    value
+        .parse::<f64>()
+        .map_err(|_| format!("Not a number: {name} {value}"))
+}
+
+/// Generate table of flow functions by command-line arguments
+fn run_table(args: &[String]) -> Result<(), String> {
+    let mut params = TableParams::default();
+    let mut format = TableFormat::Csv;
+    let mut output: Option<String> = None;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            TABLE_ARG => {}
+            "--format" => {
+                let name = it.next().ok_or("Missing value of --format")?;
+                format = TableFormat::from_name(name).ok_or(format!("Unknown format: {name}"))?;
+            }
+            "--from" => params.lambda_min = parse_value(arg, it.next())?,
+            "--to" => params.lambda_max = parse_value(arg, it.next())?,
+            "--step" => params.step = parse_value(arg, it.next())?,
+            "--k" => params.k = parse_value(arg, it.next())?,
+            "--mach" => params.mach = true,
+            "--output" => output = Some(it.next().ok_or("Missing value of --output")?.clone()),
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+
+    params.validate()?;
+    let table = flow_table::make_table(&params, format);
+    match output {
+        Some(path) => fs::write(&path, table).map_err(|e| format!("Cannot write {path}: {e}")),
+        None => {
+            print!("{table}");
+            Ok(())
+        }
+    }
+}
+
+/// Run command-line mode if it is requested. Returns exit code of the program
+pub fn run(args: &[String]) -> Option<i32> {
+    if !args.iter().any(|a| a == TABLE_ARG) {
+        return None;
+    }
+
+    match run_table(args) {
+        Ok(()) => Some(0),
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("{USAGE}");
+            Some(1)
+        }
+    }
+}
@@ -1,31 +1,83 @@
 #![allow(clippy::cast_sign_loss)]
+#![allow(clippy::collapsible_if)]
 
-mod flow_func;
-use flow_func::{DirectFunc, InverseFunc};
+use gas_dynamics::flow_func::{self, DirectFunc, InverseFunc};
+use gas_dynamics::newton::{Solution, SolverError, check_domain};
+use gas_dynamics::{duct_flow, shock};
 
+mod cli;
 mod res;
 use res::IconsAssets;
 
 use fltk::{
-    app, button, frame, group, input, menu, output,
-    prelude::{GroupExt, InputExt, MenuExt, WidgetBase, WidgetExt, WindowExt},
-    window,
+    app, button, dialog, enums, frame, group, input, menu, output,
+    prelude::{DisplayExt, GroupExt, InputExt, MenuExt, WidgetBase, WidgetExt, WindowExt},
+    text, window,
 };
+use gas_dynamics::flow_table::{self, TableFormat, TableParams};
 
-const WIDTH: i32 = 400;
-const HEIGHT: i32 = 500;
+use std::fmt;
+
+const WIDTH: i32 = 600;
+const HEIGHT: i32 = 650;
 
 const MARGIN: i32 = 10;
 const TABS_HEIGHT: i32 = 30;
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Clone, Copy)]
 enum Message {
     CalculateDirect,
     CalculateInverse,
+    CalculateShock,
+    CalculateOblique,
+    CalculateExpansion,
+    CalculateFanno,
+    CalculateRayleigh,
+    GenerateTable,
+    SaveTable,
+}
+
+/*
+ * Row with adiabatic index input and choice of gas presets
+ */
+fn make_k_row(group: &mut group::Flex) -> input::FloatInput {
+    let mut row = group::Flex::default_fill().row();
+
+    let label = frame::Frame::default().with_label("k = ");
+    row.fixed(&label, 75);
+
+    let mut in_k = input::FloatInput::default();
+    in_k.set_value(&format!("{:.4}", flow_func::K));
+    in_k.set_tooltip("Adiabatic index of the gas k");
+
+    let mut gas_choice = menu::Choice::default();
+    for (name, _) in flow_func::GAS_PRESETS {
+        gas_choice.add_choice(name);
+    }
+    gas_choice.set_tooltip("Choose the gas");
+    gas_choice.set_value(0);
+    gas_choice.set_callback({
+        let mut in_k = in_k.clone();
+        move |c| {
+            if let Some((_, k)) = flow_func::GAS_PRESETS.get(c.value() as usize) {
+                in_k.set_value(&format!("{k:.4}"));
+                set_valid(&mut in_k, true);
+            }
+        }
+    });
+    row.fixed(&gas_choice, 150);
+
+    row.end();
+
+    group.fixed(&row, 30);
+
+    in_k
 }
 
 struct DirectFuncTab {
     input: input::FloatInput,
+    in_k: input::FloatInput,
     outputs: Vec<output::Output>,
 }
 
@@ -60,6 +112,8 @@ impl DirectFuncTab {
             group.fixed(&row, 30);
         }
 
+        let in_k = make_k_row(&mut group);
+
         // Calc row
         {
             let mut row = group::Flex::default_fill().row();
@@ -106,9 +160,29 @@ impl DirectFuncTab {
 
         Self {
             input: in_lambda,
+            in_k,
             outputs,
         }
     }
+
+    fn calculate(&mut self, direct_functions: &[(&str, DirectFunc)]) {
+        if let Err(e) = self.try_calculate(direct_functions) {
+            show_error(&mut self.outputs, &e);
+        }
+    }
+
+    fn try_calculate(&mut self, direct_functions: &[(&str, DirectFunc)]) -> Result<(), CalcError> {
+        let lambda_val = read_value(&mut self.input)?;
+        let k = read_k(&mut self.in_k)?;
+        validate(&mut self.input, flow_func::check_lambda(lambda_val, k))?;
+
+        let values: Vec<f64> = direct_functions
+            .iter()
+            .map(|f| f.1(lambda_val, k))
+            .collect();
+        show_values(&mut self.outputs, &values);
+        Ok(())
+    }
 }
 
 /*
@@ -117,6 +191,7 @@ impl DirectFuncTab {
 struct InverseFuncTab {
     func_choice: menu::Choice,
     input: input::FloatInput,
+    in_k: input::FloatInput,
     out_lambda: Vec<output::Output>,
 }
 
@@ -160,6 +235,8 @@ impl InverseFuncTab {
             group.fixed(&row, 30);
         }
 
+        let in_k = make_k_row(&mut group);
+
         // Calc row
         {
             let mut row = group::Flex::default_fill().row();
@@ -180,36 +257,828 @@ impl InverseFuncTab {
             group.fixed(&row, 30);
         }
 
-        let outputs = ["lambda1", "lambda2"]
+        let outputs = make_output_rows(
+            &mut group,
+            &[
+                ("lambda1", "Dimensionless velocity lambda"),
+                ("lambda2", "Dimensionless velocity lambda"),
+                ("solver1", "Convergence of the first solution"),
+                ("solver2", "Convergence of the second solution"),
+            ],
+        );
+
+        group.end();
+
+        Self {
+            func_choice,
+            input,
+            in_k,
+            out_lambda: outputs,
+        }
+    }
+
+    fn calculate(&mut self, inverse_functions: &[(&str, InverseFunc)]) {
+        if let Err(e) = self.try_calculate(inverse_functions) {
+            show_error(&mut self.out_lambda, &e);
+        }
+    }
+
+    fn try_calculate(
+        &mut self,
+        inverse_functions: &[(&str, InverseFunc)],
+    ) -> Result<(), CalcError> {
+        let func_val = read_value(&mut self.input)?;
+        let k = read_k(&mut self.in_k)?;
+        let func_id = self.func_choice.value() as usize;
+
+        let (l1, l2) = validate(&mut self.input, inverse_functions[func_id].1(func_val, k))?;
+
+        // Show first solution
+        self.out_lambda[0].set_value(&format!("{:.4}", l1.x));
+        show_solver_info(&mut self.out_lambda[2], &l1);
+
+        // Show second solution
+        if let Some(lambda) = l2 {
+            self.out_lambda[1].activate();
+            self.out_lambda[1].set_value(&format!("{:.4}", lambda.x));
+            self.out_lambda[3].activate();
+            show_solver_info(&mut self.out_lambda[3], &lambda);
+        } else {
+            self.out_lambda[1].set_value("");
+            self.out_lambda[1].deactivate();
+            self.out_lambda[3].set_value("");
+            self.out_lambda[3].deactivate();
+        }
+        Ok(())
+    }
+}
+
+/*
+ * Common parts of the tabs
+ */
+fn make_tab_group(tabs: &group::Tabs, label: &str, tooltip: &str) -> group::Flex {
+    let mut group = group::Flex::default()
+        .column()
+        .with_size(tabs.w(), tabs.h() - TABS_HEIGHT)
+        .with_pos(tabs.x(), tabs.y() + TABS_HEIGHT)
+        .with_label(label);
+    group.set_tooltip(tooltip);
+    group.set_margin(5);
+    group
+}
+
+fn make_input_row(
+    group: &mut group::Flex,
+    label: &str,
+    value: f64,
+    tooltip: &str,
+) -> input::FloatInput {
+    let mut row = group::Flex::default_fill().row();
+
+    let label = frame::Frame::default().with_label(&format!("{label} = "));
+    row.fixed(&label, 75);
+
+    let mut input = input::FloatInput::default();
+    input.set_value(&format!("{value:.4}"));
+    input.set_tooltip(tooltip);
+
+    row.end();
+
+    group.fixed(&row, 30);
+
+    input
+}
+
+fn make_choice_input_row(
+    group: &mut group::Flex,
+    choices: &[&str],
+    value: f64,
+    tooltips: (&str, &str),
+) -> (menu::Choice, input::FloatInput) {
+    let mut row = group::Flex::default_fill().row();
+
+    let mut choice = menu::Choice::default().with_size(75, 25);
+    for c in choices {
+        choice.add_choice(c);
+    }
+    choice.set_tooltip(tooltips.0);
+    choice.set_value(0);
+    row.fixed(&choice, 75);
+
+    let label = frame::Frame::default().with_label(" = ");
+    row.fixed(&label, 25);
+
+    let mut input = input::FloatInput::default();
+    input.set_value(&format!("{value:.4}"));
+    input.set_tooltip(tooltips.1);
+
+    row.end();
+
+    group.fixed(&row, 30);
+
+    (choice, input)
+}
+
+fn make_calc_row(group: &mut group::Flex, tx: app::Sender<Message>, msg: Message) {
+    let mut row = group::Flex::default_fill().row();
+
+    frame::Frame::default();
+
+    let mut btn_calc = button::Button::default()
+        .with_size(100, 30)
+        .with_label("Calculate");
+    btn_calc.emit(tx, msg);
+
+    row.fixed(&btn_calc, 125);
+
+    frame::Frame::default();
+
+    row.end();
+
+    group.fixed(&row, 30);
+}
+
+// Rows of outputs with names and tooltips
+fn make_output_rows(group: &mut group::Flex, outputs: &[(&str, &str)]) -> Vec<output::Output> {
+    outputs
+        .iter()
+        .map(|(name, tooltip)| {
+            let mut row = group::Flex::default_fill().row();
+
+            let label = frame::Frame::default().with_label(&format!("{name} ="));
+            row.fixed(&label, 100);
+
+            let mut output = output::Output::default();
+            output.set_tooltip(tooltip);
+
+            row.end();
+
+            group.fixed(&row, 30);
+
+            output
+        })
+        .collect()
+}
+
+/*
+ * Validation of inputs
+ */
+const INPUT_COLOR: enums::Color = enums::Color::Background2;
+const INVALID_INPUT_COLOR: enums::Color = enums::Color::from_rgb(255, 200, 200);
+
+enum CalcError {
+    NotANumber(String),
+    Solver(SolverError),
+    Invalid(String),
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalcError::NotANumber(s) => write!(f, "Not a number: '{s}'"),
+            CalcError::Solver(e) => e.fmt(f),
+            CalcError::Invalid(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl From<SolverError> for CalcError {
+    fn from(e: SolverError) -> Self {
+        CalcError::Solver(e)
+    }
+}
+
+fn set_valid(input: &mut input::FloatInput, valid: bool) {
+    input.set_color(if valid {
+        INPUT_COLOR
+    } else {
+        INVALID_INPUT_COLOR
+    });
+    input.redraw();
+}
+
+// Marks the input as invalid when the calculation with its value fails
+fn validate<T>(
+    input: &mut input::FloatInput,
+    result: Result<T, SolverError>,
+) -> Result<T, CalcError> {
+    set_valid(input, result.is_ok());
+    result.map_err(CalcError::from)
+}
+
+fn read_value(input: &mut input::FloatInput) -> Result<f64, CalcError> {
+    let value = input.value();
+    match value.trim().parse::<f64>() {
+        Ok(v) if v.is_finite() => {
+            set_valid(input, true);
+            Ok(v)
+        }
+        _ => {
+            set_valid(input, false);
+            Err(CalcError::NotANumber(value))
+        }
+    }
+}
+
+fn read_k(input: &mut input::FloatInput) -> Result<f64, CalcError> {
+    let k = read_value(input)?;
+    validate(input, flow_func::check_k(k))?;
+    Ok(k)
+}
+
+fn show_values(outputs: &mut [output::Output], values: &[f64]) {
+    for (output, value) in outputs.iter_mut().zip(values) {
+        output.set_value(&format!("{value:.4}"));
+    }
+}
+
+// Error is shown in the first output, the rest are cleared
+fn show_error(outputs: &mut [output::Output], err: &CalcError) {
+    for (i, output) in outputs.iter_mut().enumerate() {
+        output.set_value(&if i == 0 {
+            err.to_string()
+        } else {
+            String::new()
+        });
+    }
+}
+
+fn show_solver_info(output: &mut output::Output, solution: &Solution) {
+    output.set_value(&format!(
+        "{} iterations, residual {:.1e}",
+        solution.iterations, solution.residual
+    ));
+}
+
+/*
+ * Normal shock wave tab
+ */
+const SHOCK_INPUTS: [&str; 2] = ["lambda1", "M1"];
+const SHOCK_OUTPUTS: [(&str, &str); 9] = [
+    ("lambda1", "Upstream dimensionless velocity"),
+    ("M1", "Upstream Mach number"),
+    ("lambda2", "Downstream dimensionless velocity"),
+    ("M2", "Downstream Mach number"),
+    ("p2/p1", "Static pressure ratio"),
+    ("T2/T1", "Static temperature ratio"),
+    ("rho2/rho1", "Density ratio"),
+    ("sigma", "Total pressure recovery p02/p01"),
+    ("ds/R", "Entropy change related to the gas constant"),
+];
+
+struct ShockTab {
+    input_choice: menu::Choice,
+    input: input::FloatInput,
+    in_k: input::FloatInput,
+    outputs: Vec<output::Output>,
+}
+
+impl ShockTab {
+    fn new(tx: app::Sender<Message>, tabs: &group::Tabs) -> Self {
+        let mut group = make_tab_group(
+            tabs,
+            "shock",
+            "Normal shock wave relations by upstream velocity",
+        );
+
+        let (input_choice, input) = make_choice_input_row(
+            &mut group,
+            &SHOCK_INPUTS,
+            1.5,
+            (
+                "Choose the upstream velocity parameter",
+                "Upstream velocity (supersonic)",
+            ),
+        );
+        let in_k = make_k_row(&mut group);
+        make_calc_row(&mut group, tx, Message::CalculateShock);
+        let outputs = make_output_rows(&mut group, &SHOCK_OUTPUTS);
+
+        group.end();
+
+        Self {
+            input_choice,
+            input,
+            in_k,
+            outputs,
+        }
+    }
+
+    fn calculate(&mut self) {
+        if let Err(e) = self.try_calculate() {
+            show_error(&mut self.outputs, &e);
+        }
+    }
+
+    fn try_calculate(&mut self) -> Result<(), CalcError> {
+        let val = read_value(&mut self.input)?;
+        let k = read_k(&mut self.in_k)?;
+
+        // Shock wave exists only in supersonic flow
+        let lambda1 = if self.input_choice.value() == 0 {
+            validate(
+                &mut self.input,
+                check_domain(val, 1.0, flow_func::lambda_max(k)),
+            )?;
+            val
+        } else {
+            validate(&mut self.input, check_domain(val, 1.0, f64::INFINITY))?;
+            validate(&mut self.input, flow_func::lambda_mach(val, k))?
+                .0
+                .x
+        };
+
+        let s = shock::normal_shock(lambda1, k);
+        show_values(
+            &mut self.outputs,
+            &[
+                s.lambda1,
+                s.mach1,
+                s.lambda2,
+                s.mach2,
+                s.pressure_ratio,
+                s.temperature_ratio,
+                s.density_ratio,
+                s.sigma,
+                s.entropy_change,
+            ],
+        );
+
+        Ok(())
+    }
+}
+
+/*
+ * Oblique shock wave tab
+ */
+const OBLIQUE_OUTPUTS: [(&str, &str); 9] = [
+    ("theta max", "Detachment flow deflection angle, degrees"),
+    (
+        "beta weak",
+        "Shock wave angle of the weak solution, degrees",
+    ),
+    (
+        "beta strong",
+        "Shock wave angle of the strong solution, degrees",
+    ),
+    ("M2 weak", "Downstream Mach number of the weak solution"),
+    ("M2 strong", "Downstream Mach number of the strong solution"),
+    ("p2/p1 weak", "Static pressure ratio of the weak solution"),
+    (
+        "p2/p1 strong",
+        "Static pressure ratio of the strong solution",
+    ),
+    ("sigma weak", "Total pressure recovery of the weak solution"),
+    (
+        "sigma strong",
+        "Total pressure recovery of the strong solution",
+    ),
+];
+
+struct ObliqueShockTab {
+    in_mach: input::FloatInput,
+    in_theta: input::FloatInput,
+    in_k: input::FloatInput,
+    outputs: Vec<output::Output>,
+}
+
+impl ObliqueShockTab {
+    fn new(tx: app::Sender<Message>, tabs: &group::Tabs) -> Self {
+        let mut group = make_tab_group(
+            tabs,
+            "oblique",
+            "Oblique shock wave by upstream Mach number and flow deflection angle",
+        );
+
+        let in_mach = make_input_row(&mut group, "M1", 2.0, "Upstream Mach number (supersonic)");
+        let in_theta = make_input_row(&mut group, "theta", 10.0, "Flow deflection angle, degrees");
+        let in_k = make_k_row(&mut group);
+        make_calc_row(&mut group, tx, Message::CalculateOblique);
+        let outputs = make_output_rows(&mut group, &OBLIQUE_OUTPUTS);
+
+        group.end();
+
+        Self {
+            in_mach,
+            in_theta,
+            in_k,
+            outputs,
+        }
+    }
+
+    fn calculate(&mut self) {
+        if let Err(e) = self.try_calculate() {
+            show_error(&mut self.outputs, &e);
+        }
+    }
+
+    fn try_calculate(&mut self) -> Result<(), CalcError> {
+        let mach1 = read_value(&mut self.in_mach)?;
+        let theta = read_value(&mut self.in_theta)?.to_radians();
+        let k = read_k(&mut self.in_k)?;
+
+        validate(&mut self.in_mach, check_domain(mach1, 1.0, f64::INFINITY))?;
+        let theta_max = shock::detachment_angle(mach1, k);
+        let (beta_weak, beta_strong) =
+            validate(&mut self.in_theta, shock::shock_angle(mach1, theta, k))?;
+        let beta_weak = beta_weak.x;
+        let beta_strong = beta_strong.map_or(f64::NAN, |b| b.x);
+
+        let weak = shock::oblique_shock(mach1, beta_weak, k);
+        let strong = shock::oblique_shock(mach1, beta_strong, k);
+
+        show_values(
+            &mut self.outputs,
+            &[
+                theta_max.to_degrees(),
+                beta_weak.to_degrees(),
+                beta_strong.to_degrees(),
+                weak.mach2,
+                strong.mach2,
+                weak.normal.pressure_ratio,
+                strong.normal.pressure_ratio,
+                weak.normal.sigma,
+                strong.normal.sigma,
+            ],
+        );
+
+        Ok(())
+    }
+}
+
+/*
+ * Prandtl-Meyer expansion tab
+ */
+const EXPANSION_INPUTS: [&str; 2] = ["M", "nu"];
+const EXPANSION_OUTPUTS: [(&str, &str); 4] = [
+    ("M", "Mach number"),
+    ("nu", "Prandtl-Meyer angle, degrees"),
+    ("mu", "Mach angle, degrees"),
+    ("nu max", "Maximal turning angle of the flow, degrees"),
+];
+
+struct ExpansionTab {
+    input_choice: menu::Choice,
+    input: input::FloatInput,
+    in_k: input::FloatInput,
+    outputs: Vec<output::Output>,
+}
+
+impl ExpansionTab {
+    fn new(tx: app::Sender<Message>, tabs: &group::Tabs) -> Self {
+        let mut group = make_tab_group(tabs, "expansion", "Prandtl-Meyer function and its inverse");
+
+        let (input_choice, input) = make_choice_input_row(
+            &mut group,
+            &EXPANSION_INPUTS,
+            2.0,
+            (
+                "Choose Mach number or Prandtl-Meyer angle",
+                "Mach number or Prandtl-Meyer angle in degrees",
+            ),
+        );
+        let in_k = make_k_row(&mut group);
+        make_calc_row(&mut group, tx, Message::CalculateExpansion);
+        let outputs = make_output_rows(&mut group, &EXPANSION_OUTPUTS);
+
+        group.end();
+
+        Self {
+            input_choice,
+            input,
+            in_k,
+            outputs,
+        }
+    }
+
+    fn calculate(&mut self) {
+        if let Err(e) = self.try_calculate() {
+            show_error(&mut self.outputs, &e);
+        }
+    }
+
+    fn try_calculate(&mut self) -> Result<(), CalcError> {
+        let val = read_value(&mut self.input)?;
+        let k = read_k(&mut self.in_k)?;
+
+        let mach = if self.input_choice.value() == 0 {
+            validate(&mut self.input, check_domain(val, 1.0, f64::INFINITY))?;
+            val
+        } else {
+            validate(
+                &mut self.input,
+                shock::mach_prandtl_meyer(val.to_radians(), k),
+            )?
+            .0
+            .x
+        };
+
+        show_values(
+            &mut self.outputs,
+            &[
+                mach,
+                shock::prandtl_meyer(mach, k).to_degrees(),
+                shock::mach_angle(mach).to_degrees(),
+                shock::prandtl_meyer_max(k).to_degrees(),
+            ],
+        );
+
+        Ok(())
+    }
+}
+
+/*
+ * Tab with duct flow parameters. Input is either velocity or one of the
+ * parameters, outputs are shown for both solutions of inverse functions
+ */
+struct DuctFlowTab {
+    input_choice: menu::Choice,
+    input: input::FloatInput,
+    in_k: input::FloatInput,
+    outputs: Vec<(output::Output, output::Output)>,
+}
+
+impl DuctFlowTab {
+    fn new(
+        labels: (&str, &str),
+        direct_functions: &[(&str, DirectFunc)],
+        inverse_functions: &[(&str, InverseFunc)],
+        tx: app::Sender<Message>,
+        msg: Message,
+        tabs: &group::Tabs,
+    ) -> Self {
+        let mut group = make_tab_group(tabs, labels.0, labels.1);
+
+        let choices: Vec<&str> = inverse_functions.iter().map(|f| f.0).collect();
+        let (input_choice, input) = make_choice_input_row(
+            &mut group,
+            &choices,
+            0.5,
+            ("Choose the input parameter", "Value of the input parameter"),
+        );
+        let in_k = make_k_row(&mut group);
+        make_calc_row(&mut group, tx, msg);
+
+        // Header of solutions
+        {
+            let mut row = group::Flex::default_fill().row();
+
+            let label = frame::Frame::default();
+            row.fixed(&label, 100);
+
+            frame::Frame::default().with_label("lambda1");
+            frame::Frame::default().with_label("lambda2");
+
+            row.end();
+
+            group.fixed(&row, 30);
+        }
+
+        let outputs = direct_functions
             .iter()
-            .map(|s| {
+            .map(|f| {
                 let mut row = group::Flex::default_fill().row();
 
-                let label = frame::Frame::default().with_label(&format!("{s} ="));
-                row.fixed(&label, 75);
+                let label = frame::Frame::default().with_label(&format!("{} =", f.0));
+                row.fixed(&label, 100);
 
-                let mut output = output::Output::default();
-                output.set_tooltip("Dimensionless velocity lambda");
+                let mut output1 = output::Output::default();
+                output1.set_tooltip(&format!("{} of the first solution", f.0));
+                let mut output2 = output::Output::default();
+                output2.set_tooltip(&format!("{} of the second solution", f.0));
 
                 row.end();
 
                 group.fixed(&row, 30);
 
-                output
+                (output1, output2)
             })
             .collect();
 
         group.end();
 
         Self {
-            func_choice,
+            input_choice,
             input,
-            out_lambda: outputs,
+            in_k,
+            outputs,
+        }
+    }
+
+    fn calculate(
+        &mut self,
+        direct_functions: &[(&str, DirectFunc)],
+        inverse_functions: &[(&str, InverseFunc)],
+    ) {
+        let func_id = self.input_choice.value() as usize;
+        let solutions = read_value(&mut self.input).and_then(|val| {
+            let k = read_k(&mut self.in_k)?;
+            Ok((
+                validate(&mut self.input, inverse_functions[func_id].1(val, k))?,
+                k,
+            ))
+        });
+
+        let ((l1, l2), k) = match solutions {
+            Ok(solutions) => solutions,
+            Err(e) => {
+                let mut outputs: Vec<output::Output> =
+                    self.outputs.iter().map(|o| o.0.clone()).collect();
+                show_error(&mut outputs, &e);
+                for (_, output2) in &mut self.outputs {
+                    output2.set_value("");
+                }
+                return;
+            }
+        };
+
+        for (i, f) in direct_functions.iter().enumerate() {
+            let (output1, output2) = &mut self.outputs[i];
+
+            output1.set_value(&format!("{:.4}", f.1(l1.x, k)));
+
+            if let Some(lambda) = l2 {
+                output2.activate();
+                output2.set_value(&format!("{:.4}", f.1(lambda.x, k)));
+            } else {
+                output2.set_value("");
+                output2.deactivate();
+            }
+        }
+    }
+}
+
+/*
+ * Tab with generator of tables of flow functions
+ */
+const TABLE_FORMAT_NAMES: [&str; 3] = ["CSV", "Markdown", "LaTeX"];
+
+struct TableTab {
+    in_from: input::FloatInput,
+    in_to: input::FloatInput,
+    in_step: input::FloatInput,
+    in_k: input::FloatInput,
+    format_choice: menu::Choice,
+    mach_check: button::CheckButton,
+    buffer: text::TextBuffer,
+}
+
+impl TableTab {
+    fn new(tx: app::Sender<Message>, tabs: &group::Tabs) -> Self {
+        let mut group = make_tab_group(
+            tabs,
+            "table",
+            "Generate table of flow functions over a range of lambda",
+        );
+
+        let defaults = TableParams::default();
+        let in_from = make_input_row(
+            &mut group,
+            "from",
+            defaults.lambda_min,
+            "First value of lambda",
+        );
+        let in_to = make_input_row(
+            &mut group,
+            "to",
+            defaults.lambda_max,
+            "Last value of lambda",
+        );
+        let in_step = make_input_row(&mut group, "step", defaults.step, "Step of lambda");
+        let in_k = make_k_row(&mut group);
+
+        // Options row
+        let mut format_choice;
+        let mut mach_check;
+        {
+            let mut row = group::Flex::default_fill().row();
+
+            let label = frame::Frame::default().with_label("format = ");
+            row.fixed(&label, 75);
+
+            format_choice = menu::Choice::default();
+            for name in TABLE_FORMAT_NAMES {
+                format_choice.add_choice(name);
+            }
+            format_choice.set_tooltip("Output format of the table");
+            format_choice.set_value(0);
+
+            mach_check = button::CheckButton::default().with_label("Mach number");
+            mach_check.set_tooltip("Add column with Mach number");
+            row.fixed(&mach_check, 150);
+
+            row.end();
+
+            group.fixed(&row, 30);
+        }
+
+        // Buttons row
+        {
+            let mut row = group::Flex::default_fill().row();
+
+            frame::Frame::default();
+
+            let mut btn_generate = button::Button::default().with_label("Generate");
+            btn_generate.emit(tx, Message::GenerateTable);
+            row.fixed(&btn_generate, 125);
+
+            let mut btn_save = button::Button::default().with_label("Save...");
+            btn_save.emit(tx, Message::SaveTable);
+            row.fixed(&btn_save, 125);
+
+            frame::Frame::default();
+
+            row.end();
+
+            group.fixed(&row, 30);
+        }
+
+        // Preview of the table takes the rest of the tab
+        let buffer = text::TextBuffer::default();
+        let mut disp = text::TextDisplay::default();
+        disp.set_buffer(buffer.clone());
+        disp.set_text_font(fltk::enums::Font::Courier);
+
+        group.end();
+
+        Self {
+            in_from,
+            in_to,
+            in_step,
+            in_k,
+            format_choice,
+            mach_check,
+            buffer,
+        }
+    }
+
+    fn format(&self) -> TableFormat {
+        TableFormat::ALL[self.format_choice.value() as usize]
+    }
+
+    fn generate(&mut self) -> Result<String, CalcError> {
+        match self.try_generate() {
+            Ok(table) => {
+                self.buffer.set_text(&table);
+                Ok(table)
+            }
+            Err(e) => {
+                self.buffer.set_text(&e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    fn try_generate(&mut self) -> Result<String, CalcError> {
+        let lambda_min = read_value(&mut self.in_from)?;
+        let lambda_max = read_value(&mut self.in_to)?;
+        let step = read_value(&mut self.in_step)?;
+        let k = read_k(&mut self.in_k)?;
+
+        let params = TableParams {
+            lambda_min,
+            lambda_max,
+            step,
+            k,
+            mach: self.mach_check.is_checked(),
+        };
+        params.validate().map_err(CalcError::Invalid)?;
+
+        Ok(flow_table::make_table(&params, self.format()))
+    }
+
+    fn save(&mut self) {
+        let table = match self.generate() {
+            Ok(table) => table,
+            Err(e) => {
+                dialog::alert_default(&format!("Cannot generate the table: {e}"));
+                return;
+            }
+        };
+
+        let mut chooser = dialog::NativeFileChooser::new(dialog::FileDialogType::BrowseSaveFile);
+        chooser.set_option(dialog::FileDialogOptions::SaveAsConfirm);
+        chooser.set_filter(&format!("*.{}", self.format().name()));
+        chooser.set_preset_file(&format!("flow_table.{}", self.format().name()));
+        chooser.show();
+
+        let path = chooser.filename();
+        if path.as_os_str().is_empty() {
+            return;
+        }
+
+        if let Err(e) = std::fs::write(&path, table) {
+            dialog::alert_default(&format!("Cannot write {}: {e}", path.display()));
         }
     }
 }
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(code) = cli::run(&args) {
+        std::process::exit(code);
+    }
+
     let direct_functions: Vec<(&str, DirectFunc)> = vec![
         ("tau", flow_func::tau),
         ("pi", flow_func::pi),
@@ -217,6 +1086,11 @@ fn main() {
         ("q", flow_func::q),
         ("phi", flow_func::phi),
         ("y", flow_func::y),
+        ("M", flow_func::mach),
+        ("z", flow_func::z),
+        ("f", flow_func::f),
+        ("r", flow_func::r),
+        ("A/A*", flow_func::area_ratio),
     ];
 
     let inverse_functions: Vec<(&str, InverseFunc)> = vec![
@@ -226,6 +1100,50 @@ fn main() {
         ("q", flow_func::lambda_q),
         ("phi", flow_func::lambda_phi),
         ("y", flow_func::lambda_y),
+        ("M", flow_func::lambda_mach),
+        ("z", flow_func::lambda_z),
+        ("f", flow_func::lambda_f),
+        ("r", flow_func::lambda_r),
+        ("A/A*", flow_func::lambda_area_ratio),
+    ];
+
+    let fanno_functions: Vec<(&str, DirectFunc)> = vec![
+        ("lambda", |lambda, _| lambda),
+        ("M", flow_func::mach),
+        ("4fL*/D", duct_flow::fanno_friction),
+        ("p/p*", duct_flow::fanno_pressure),
+        ("T/T*", duct_flow::fanno_temperature),
+        ("p0/p0*", duct_flow::fanno_total_pressure),
+    ];
+
+    let fanno_inverse_functions: Vec<(&str, InverseFunc)> = vec![
+        ("lambda", |lambda, k| {
+            flow_func::check_lambda(lambda, k).map(|()| (Solution::exact(lambda), None))
+        }),
+        ("M", flow_func::lambda_mach),
+        ("4fL*/D", duct_flow::lambda_fanno_friction),
+        ("p/p*", duct_flow::lambda_fanno_pressure),
+        ("T/T*", duct_flow::lambda_fanno_temperature),
+        ("p0/p0*", duct_flow::lambda_fanno_total_pressure),
+    ];
+
+    let rayleigh_functions: Vec<(&str, DirectFunc)> = vec![
+        ("lambda", |lambda, _| lambda),
+        ("M", flow_func::mach),
+        ("T0/T0*", duct_flow::rayleigh_total_temperature),
+        ("p/p*", duct_flow::rayleigh_pressure),
+        ("T/T*", duct_flow::rayleigh_temperature),
+        ("p0/p0*", duct_flow::rayleigh_total_pressure),
+    ];
+
+    let rayleigh_inverse_functions: Vec<(&str, InverseFunc)> = vec![
+        ("lambda", |lambda, k| {
+            flow_func::check_lambda(lambda, k).map(|()| (Solution::exact(lambda), None))
+        }),
+        ("M", flow_func::lambda_mach),
+        ("T0/T0*", duct_flow::lambda_rayleigh_total_temperature),
+        ("p/p*", duct_flow::lambda_rayleigh_pressure),
+        ("T/T*", duct_flow::lambda_rayleigh_temperature),
     ];
 
     let a = app::App::default();
@@ -247,6 +1165,41 @@ fn main() {
     // Tab with inverse calculations
     let mut inverse_tab = InverseFuncTab::new(&inverse_functions, tx, &tabs);
 
+    // Tab with normal shock wave
+    let mut shock_tab = ShockTab::new(tx, &tabs);
+
+    // Tab with oblique shock wave
+    let mut oblique_tab = ObliqueShockTab::new(tx, &tabs);
+
+    // Tab with Prandtl-Meyer expansion
+    let mut expansion_tab = ExpansionTab::new(tx, &tabs);
+
+    // Tab with flow in a duct with friction
+    let mut fanno_tab = DuctFlowTab::new(
+        ("fanno", "Fanno flow: flow in a duct with friction"),
+        &fanno_functions,
+        &fanno_inverse_functions,
+        tx,
+        Message::CalculateFanno,
+        &tabs,
+    );
+
+    // Tab with flow in a duct with heat addition
+    let mut rayleigh_tab = DuctFlowTab::new(
+        (
+            "rayleigh",
+            "Rayleigh flow: flow in a duct with heat addition",
+        ),
+        &rayleigh_functions,
+        &rayleigh_inverse_functions,
+        tx,
+        Message::CalculateRayleigh,
+        &tabs,
+    );
+
+    // Tab with tables of flow functions
+    let mut table_tab = TableTab::new(tx, &tabs);
+
     tabs.end();
 
     if let Some(img) = IconsAssets::get("FluidCalc32.png") {
@@ -261,40 +1214,22 @@ fn main() {
     while a.wait() {
         if let Some(msg) = rx.recv() {
             match msg {
-                Message::CalculateDirect => {
-                    let lambda_val = direct_tab
-                        .input
-                        .value()
-                        .parse::<f64>()
-                        .expect("Not a number!");
-
-                    for (i, f) in direct_functions.iter().enumerate() {
-                        let result = f.1(lambda_val);
-                        direct_tab.outputs[i].set_value(&format!("{result:.4}"));
-                    }
+                Message::CalculateDirect => direct_tab.calculate(&direct_functions),
+                Message::CalculateInverse => inverse_tab.calculate(&inverse_functions),
+                Message::CalculateShock => shock_tab.calculate(),
+                Message::CalculateOblique => oblique_tab.calculate(),
+                Message::CalculateExpansion => expansion_tab.calculate(),
+                Message::CalculateFanno => {
+                    fanno_tab.calculate(&fanno_functions, &fanno_inverse_functions);
+                }
+                Message::CalculateRayleigh => {
+                    rayleigh_tab.calculate(&rayleigh_functions, &rayleigh_inverse_functions);
                 }
-                Message::CalculateInverse => {
-                    let func_val: f64 = inverse_tab
-                        .input
-                        .value()
-                        .parse::<f64>()
-                        .expect("Not a number!");
-                    let func_id: i32 = inverse_tab.func_choice.value();
-
-                    let (l1, l2) = inverse_functions[func_id as usize].1(func_val);
-
-                    // Show first solution
-                    inverse_tab.out_lambda[0].set_value(&format!("{l1:.4}"));
-
-                    // Show second solution
-                    if let Some(lambda) = l2 {
-                        inverse_tab.out_lambda[1].activate();
-                        inverse_tab.out_lambda[1].set_value(&format!("{lambda:.4}"));
-                    } else {
-                        inverse_tab.out_lambda[1].set_value("");
-                        inverse_tab.out_lambda[1].deactivate();
-                    }
+                Message::GenerateTable => {
+                    // Errors are shown in the preview
+                    let _ = table_tab.generate();
                 }
+                Message::SaveTable => table_tab.save(),
             }
         }
     }
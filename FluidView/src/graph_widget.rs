@@ -1,282 +1,254 @@
-#![allow(clippy::cast_lossless)]
-#![allow(clippy::cast_possible_truncation)]
-#![allow(clippy::too_many_lines)]
+#![allow(clippy::cast_precision_loss)]
 
-use fltk::{
-    draw, enums,
-    prelude::{WidgetBase, WidgetExt},
-    widget, widget_extends,
-};
+use fltk::{enums, prelude::WidgetExt, widget_extends};
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use crate::fluid_func;
-
-struct Point {
-    x: f64,
-    y: f64,
-}
-
-struct Tick {
-    begin: Point,
-    end: Point,
-}
-
-struct Area {
-    xmin: f64,
-    ymin: f64,
-    xmax: f64,
-    ymax: f64,
+use gas_dynamics::flow_func::{self, DirectFunc};
+use gas_dynamics::newton::Trace;
+use mechanics_common::plot_area::{Area, Point};
+use mechanics_common::plot_widget::{Guide, Marker, PlotData, PlotWidget};
+
+const POINT_COUNT: usize = 500;
+
+const PLOT_COLOR: enums::Color = enums::Color::from_rgb(255, 25, 50);
+const VALUE_LINE_COLOR: enums::Color = enums::Color::from_rgb(32, 128, 32);
+const LAMBDA_LINE_COLOR: enums::Color = enums::Color::from_rgb(0, 64, 192);
+const PATH_COLOR: enums::Color = enums::Color::from_rgb(255, 160, 0);
+const READOUT_COLOR: enums::Color = enums::Color::Black;
+
+/// Flow function shown on the graph with the upper bound of the plot
+/// and the default value to solve for
+#[derive(Copy, Clone)]
+pub struct GraphFunction {
+    pub name: &'static str,
+    pub func: DirectFunc,
+    pub ymax: f64,
+    pub default: f64,
 }
 
+pub const FUNCTIONS: [GraphFunction; 11] = [
+    GraphFunction {
+        name: "tau",
+        func: flow_func::tau,
+        ymax: 1.0,
+        default: 0.5,
+    },
+    GraphFunction {
+        name: "pi",
+        func: flow_func::pi,
+        ymax: 1.0,
+        default: 0.5,
+    },
+    GraphFunction {
+        name: "eps",
+        func: flow_func::eps,
+        ymax: 1.0,
+        default: 0.5,
+    },
+    GraphFunction {
+        name: "q",
+        func: flow_func::q,
+        ymax: 1.0,
+        default: 0.5,
+    },
+    GraphFunction {
+        name: "phi",
+        func: flow_func::phi,
+        ymax: 5.0,
+        default: 1.5,
+    },
+    GraphFunction {
+        name: "y",
+        func: flow_func::y,
+        ymax: 5.0,
+        default: 1.0,
+    },
+    GraphFunction {
+        name: "M",
+        func: flow_func::mach,
+        ymax: 5.0,
+        default: 1.0,
+    },
+    GraphFunction {
+        name: "z",
+        func: flow_func::z,
+        ymax: 5.0,
+        default: 2.5,
+    },
+    GraphFunction {
+        name: "f",
+        func: flow_func::f,
+        ymax: 1.5,
+        default: 1.1,
+    },
+    GraphFunction {
+        name: "r",
+        func: flow_func::r,
+        ymax: 1.0,
+        default: 0.5,
+    },
+    GraphFunction {
+        name: "A/A*",
+        func: flow_func::area_ratio,
+        ymax: 5.0,
+        default: 2.0,
+    },
+];
+
+type ValueCallback = Box<dyn FnMut(f64)>;
+
+#[derive(Clone)]
 pub struct GraphWidget {
-    inner: widget::Widget,
-    q: Rc<RefCell<f64>>,
-    lambda1: Rc<RefCell<f64>>,
-    lambda2: Rc<RefCell<f64>>,
+    inner: PlotWidget,
+    function: Rc<RefCell<GraphFunction>>,
+    on_value: Rc<RefCell<Option<ValueCallback>>>,
 }
 
 impl GraphWidget {
     pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
-        const MARGIN: i32 = 20;
-        const TICK_SIZE: i32 = 10;
-
-        let point_count: i32 = 500;
-        let tick_count: i32 = 20;
-
-        let bg_color = enums::Color::White;
-        let bounds_color = enums::Color::Black;
-        let ticks_color = enums::Color::Black;
-
-        let plot_color = enums::Color::from_rgb(255, 25, 50);
-        let q_line_color = enums::Color::from_rgb(32, 128, 32);
-        let lambda_line_color = enums::Color::from_rgb(0, 64, 192);
+        let mut inner = PlotWidget::new(x, y, width, height);
+
+        let function = Rc::from(RefCell::from(FUNCTIONS[0]));
+        let on_value: Rc<RefCell<Option<ValueCallback>>> = Rc::from(RefCell::from(None));
+
+        inner.handle_mouse({
+            let mut plot = inner.clone();
+            let function = function.clone();
+            let on_value = on_value.clone();
+            move |ev, p| match ev {
+                // Click or drag chooses the value at the height of the cursor
+                enums::Event::Push | enums::Event::Drag => {
+                    let area = plot.get_area();
+                    if let Some(f) = on_value.borrow_mut().as_mut() {
+                        f(p.y.clamp(area.ymin, area.ymax));
+                    }
+                    true
+                }
+                // Readout of the function under the cursor
+                enums::Event::Move => {
+                    let function = function.borrow();
+                    let lambda = p.x.clamp(0.0, flow_func::lambda_max(flow_func::K));
+                    let value = (function.func)(lambda, flow_func::K);
+                    plot.set_markers(vec![Marker {
+                        point: Point {
+                            x: lambda,
+                            y: value,
+                        },
+                        color: READOUT_COLOR,
+                        label: format!("lambda = {lambda:.4}, {} = {value:.4}", function.name),
+                    }]);
+                    plot.redraw();
+                    true
+                }
+                enums::Event::Leave => {
+                    plot.set_markers(vec![]);
+                    plot.redraw();
+                    true
+                }
+                _ => false,
+            }
+        });
 
-        let mut inner = widget::Widget::default()
-            .with_pos(x, y)
-            .with_size(width, height);
+        let mut graph = Self {
+            inner,
+            function,
+            on_value,
+        };
+        graph.set_function(FUNCTIONS[0]);
+        graph
+    }
 
-        let max_lambda: f64 = ((fluid_func::K + 1.0) / (fluid_func::K - 1.0)).sqrt();
+    pub fn function(&self) -> GraphFunction {
+        *self.function.borrow()
+    }
 
-        let q: f64 = 0.0;
-        let lambda1: f64 = 0.0;
-        let lambda2: f64 = 0.0;
-        let area: Area = Area {
+    pub fn set_function(&mut self, function: GraphFunction) {
+        let lambda_max = flow_func::lambda_max(flow_func::K);
+        let area = Area {
             xmin: 0.0,
             ymin: 0.0,
-            xmax: max_lambda,
-            ymax: 1.0,
+            xmax: lambda_max,
+            ymax: function.ymax,
         };
-
-        let q = Rc::from(RefCell::from(q));
-        let lambda1 = Rc::from(RefCell::from(lambda1));
-        let lambda2 = Rc::from(RefCell::from(lambda2));
-
-        let q_val = q.clone();
-        let lambda1_val = lambda1.clone();
-        let lambda2_val = lambda2.clone();
+        self.inner.set_area(area);
+        self.inner.set_axis_labels("lambda", function.name);
 
         // Calculate plot points
-        let mut point_vec: Vec<Point> = Vec::new();
-        let px: f64 = (area.xmax - area.xmin) / (point_count as f64);
-        for i in 0..=point_count {
-            let xc: f64 = area.xmin + px * (i as f64);
-            point_vec.push(Point {
-                x: xc,
-                y: fluid_func::q(xc),
-            });
-        }
-
-        inner.draw(move |i| {
-            // Calculate pixel scale
-            let scale_x: f64 = (area.xmax - area.xmin) / ((i.w() - MARGIN * 2 - TICK_SIZE) as f64);
-            let scale_y: f64 = (area.ymax - area.ymin) / ((i.h() - MARGIN * 2 - TICK_SIZE) as f64);
-
-            // Helper functions
-            let get_x =
-                |x: f64| -> i32 { ((x - area.xmin) / scale_x) as i32 + i.x() + MARGIN + TICK_SIZE };
-            let get_y = |y: f64| -> i32 { ((area.ymax - y) / scale_y) as i32 + i.y() + MARGIN };
-            let get_coord =
-                |x: f64, y: f64| -> draw::Coord<i32> { draw::Coord::<i32>(get_x(x), get_y(y)) };
+        let points = (0..=POINT_COUNT)
+            .map(|i| {
+                let x = lambda_max * (i as f64) / (POINT_COUNT as f64);
+                Point {
+                    x,
+                    y: (function.func)(x, flow_func::K),
+                }
+            })
+            .collect();
+        self.inner.set_data(vec![PlotData {
+            points,
+            color: PLOT_COLOR,
+            name: format!("{}(lambda)", function.name),
+        }]);
+
+        self.inner.set_guides(vec![]);
+        self.inner.set_markers(vec![]);
+        *self.function.borrow_mut() = function;
+    }
 
-            // Calculate ticks
+    // Line of the value, lines of the solutions and first `steps` steps of Newton's method:
+    // from the approximation to the function and along the tangent to the next one.
+    // Solution is shown after all steps leading to it, `None` shows all steps
+    pub fn set_solutions(&mut self, value: f64, traces: &[Trace], steps: Option<usize>) {
+        let area = self.inner.get_area();
+        let func = self.function.borrow().func;
+        let point = |x: f64, y: f64| Point {
+            x,
+            y: y.clamp(area.ymin, area.ymax),
+        };
 
-            // X-Axis ticks
-            let mut ticks_x: Vec<Tick> = Vec::new();
-            let tx: f64 = (area.xmax - area.xmin) / (tick_count as f64);
-            for i in 0..=tick_count {
-                let xc: f64 = area.xmin + tx * (i as f64);
-                let tick_scale: f64 = if i % 2 == 0 { 1.0 } else { 0.5 };
-                ticks_x.push(Tick {
-                    begin: Point {
-                        x: xc,
-                        y: area.ymin,
-                    },
-                    end: Point {
-                        x: xc,
-                        y: area.ymin - (TICK_SIZE as f64) * scale_y * tick_scale,
-                    },
+        let mut guides: Vec<Guide> = vec![Guide {
+            line: (point(area.xmin, value), point(area.xmax, value)),
+            color: VALUE_LINE_COLOR,
+        }];
+
+        for t in traces {
+            let steps = steps.unwrap_or(t.path.len());
+            for step in t.path.windows(2).take(steps) {
+                let y = func(step[0], flow_func::K);
+                guides.push(Guide {
+                    line: (point(step[0], value), point(step[0], y)),
+                    color: PATH_COLOR,
                 });
-            }
-
-            // Y-Axis ticks
-            let mut ticks_y: Vec<Tick> = Vec::new();
-            let ty: f64 = (area.ymax - area.ymin) / (tick_count as f64);
-            for i in 0..=tick_count {
-                let yc: f64 = area.ymin + ty * (i as f64);
-                let tick_scale: f64 = if i % 2 == 0 { 1.0 } else { 0.5 };
-                ticks_y.push(Tick {
-                    begin: Point {
-                        x: area.xmin,
-                        y: yc,
-                    },
-                    end: Point {
-                        x: area.xmin - (TICK_SIZE as f64) * scale_x * tick_scale,
-                        y: yc,
-                    },
+                guides.push(Guide {
+                    line: (point(step[0], y), point(step[1], value)),
+                    color: PATH_COLOR,
                 });
             }
 
-            // Clean draw area with backgorund color
-            draw::draw_rect_fill(i.x(), i.y(), i.w(), i.h(), bg_color);
-
-            // Draw bounding box
-            draw::set_draw_color(bounds_color);
-            draw::draw_loop3(
-                get_coord(area.xmin, area.ymin),
-                get_coord(area.xmax, area.ymin),
-                get_coord(area.xmax, area.ymax),
-                get_coord(area.xmin, area.ymax),
-            );
-
-            // Draw ticks
-            draw::set_draw_color(ticks_color);
-
-            for t in &ticks_x {
-                draw::draw_line(
-                    get_x(t.begin.x),
-                    get_y(t.begin.y),
-                    get_x(t.end.x),
-                    get_y(t.end.y),
-                );
-            }
-
-            for t in &ticks_y {
-                draw::draw_line(
-                    get_x(t.begin.x),
-                    get_y(t.begin.y),
-                    get_x(t.end.x),
-                    get_y(t.end.y),
-                );
-            }
-
-            // Draw ranges
-            draw::set_draw_color(bounds_color);
-            draw::set_font(enums::Font::Helvetica, 12);
-
-            let xmin_str = format!("{:.1}", area.xmin);
-            draw::draw_text2(
-                &xmin_str,
-                get_x(area.xmin),
-                get_y(area.ymin - scale_y * ((MARGIN + TICK_SIZE) as f64)),
-                0,
-                0,
-                enums::Align::BottomLeft,
-            );
-
-            let xmax_str = format!("{:.1}", area.xmax);
-            draw::draw_text2(
-                &xmax_str,
-                get_x(area.xmax),
-                get_y(area.ymin - scale_y * ((MARGIN + TICK_SIZE) as f64)),
-                0,
-                0,
-                enums::Align::BottomRight,
-            );
-
-            let ymin_str = format!("{:.1}", area.ymin);
-            draw::draw_text2(
-                &ymin_str,
-                get_x(area.xmin - scale_x * (MARGIN + TICK_SIZE) as f64),
-                get_y(area.ymin),
-                0,
-                0,
-                enums::Align::BottomLeft,
-            );
-
-            let ymax_str = format!("{:.1}", area.ymax);
-            draw::draw_text2(
-                &ymax_str,
-                get_x(area.xmin - scale_x * (MARGIN + TICK_SIZE) as f64),
-                get_y(area.ymax),
-                0,
-                0,
-                enums::Align::TopLeft,
-            );
-
-            // Draw labels
-            let x_label = "lambda";
-            let y_label = "q";
-
-            draw::set_font(enums::Font::HelveticaBold, 14);
-
-            draw::draw_text2(
-                x_label,
-                get_x(area.xmin + (area.xmax - area.xmin) * 0.5),
-                get_y(area.ymin - scale_y * ((TICK_SIZE as f64) * 1.5)),
-                0,
-                0,
-                enums::Align::Center | enums::Align::Top,
-            );
-
-            draw::draw_text2(
-                y_label,
-                get_x(area.xmin - scale_x * (TICK_SIZE as f64) * 1.5),
-                get_y(area.ymin + (area.ymax - area.ymin) * 0.5),
-                0,
-                0,
-                enums::Align::Right,
-            );
-
-            // Draw plot
-            draw::set_draw_color(plot_color);
-            draw::begin_line();
-            for c in &point_vec {
-                draw::vertex(get_x(c.x) as f64, get_y(c.y) as f64);
+            if steps + 1 >= t.path.len() {
+                guides.push(Guide {
+                    line: (point(t.solution.x, area.ymin), point(t.solution.x, value)),
+                    color: LAMBDA_LINE_COLOR,
+                });
             }
-            draw::end_line();
-
-            // Draw lines
-            let q: f64 = *q_val.borrow();
-            if q > 0.0 {
-                draw::set_draw_color(q_line_color);
-                draw::draw_line(get_x(area.xmin), get_y(q), get_x(area.xmax), get_y(q));
-
-                let lambda1: f64 = *lambda1_val.borrow();
-                let lambda2: f64 = *lambda2_val.borrow();
+        }
 
-                draw::set_draw_color(lambda_line_color);
-                draw::draw_line(get_x(lambda1), get_y(area.ymin), get_x(lambda1), get_y(q));
-                draw::draw_line(get_x(lambda2), get_y(area.ymin), get_x(lambda2), get_y(q));
-            }
-        });
+        self.inner.set_guides(guides);
+    }
 
-        inner.handle(move |_i, _ev| false);
+    pub fn clear_lines(&mut self) {
+        self.inner.set_guides(vec![]);
+    }
 
-        Self {
-            inner,
-            q,
-            lambda1,
-            lambda2,
-        }
+    // Called with the value of the function chosen with the mouse
+    pub fn set_value_callback<F: FnMut(f64) + 'static>(&mut self, f: F) {
+        *self.on_value.borrow_mut() = Some(Box::new(f));
     }
 
-    pub fn set_lines(&mut self, new_q: f64, new_lambda1: f64, new_lambda2: f64) {
-        *self.q.borrow_mut() = new_q;
-        *self.lambda1.borrow_mut() = new_lambda1;
-        *self.lambda2.borrow_mut() = new_lambda2;
+    pub fn redraw(&mut self) {
+        self.inner.redraw();
     }
 }
 
-widget_extends!(GraphWidget, widget::Widget, inner);
+widget_extends!(GraphWidget, PlotWidget, inner);
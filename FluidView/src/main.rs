@@ -1,76 +1,509 @@
-mod fluid_func;
+#![allow(clippy::collapsible_if)]
+
 mod graph_widget;
+mod nozzle_widget;
 mod res;
 
 use fltk::{
-    app, button, input,
-    prelude::{DisplayExt, GroupExt, InputExt, WidgetExt, WindowExt},
+    app, button, dialog, enums, group, input, menu,
+    prelude::{DisplayExt, GroupExt, InputExt, MenuExt, WidgetExt, WindowExt},
     text, window,
 };
+use gas_dynamics::flow_func;
+use gas_dynamics::newton::{self, SolverError, Trace, check_domain};
+use gas_dynamics::nozzle::{self, AreaProfile, NozzleRegime};
 use res::IconsAssets;
 
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread;
+use std::time::Duration;
+
 const WIDTH: i32 = 700;
-const HEIGHT: i32 = 500;
+const HEIGHT: i32 = 530;
 
-const DEFAULT_Q_VALUE: f64 = 0.5;
+const TABS_HEIGHT: i32 = 30;
 
-fn main() {
-    let a = app::App::default();
-    app::get_system_colors();
+// Mass flow density q(lambda)
+const DEFAULT_FUNCTION: usize = 3;
 
-    let mut wind = window::Window::default()
-        .with_size(WIDTH, HEIGHT)
-        .with_label("Fluid Flow Visual Calculator");
-    wind.make_resizable(true);
+// Range of the tolerance of Newton's method
+const EPS_MIN: f64 = 1e-12;
+const EPS_MAX: f64 = 1e-1;
+
+// Delay between the steps of the animation, ms
+const ANIMATION_DT: u64 = 700;
+
+const DEFAULT_INLET_AREA: f64 = 4.0;
+const DEFAULT_EXIT_AREA: f64 = 2.0;
+const DEFAULT_THROAT_X: f64 = 0.4;
+const DEFAULT_BACK_PRESSURE: f64 = 0.8;
+
+// Throat is kept away from the ends of the nozzle
+const THROAT_X_MIN: f64 = 0.05;
+const THROAT_X_MAX: f64 = 0.95;
+
+const PROFILE_PRESETS: [&str; 3] = ["Conical", "Bell", "Tabulated"];
+const PRESET_TABULATED: i32 = 2;
+
+#[derive(Copy, Clone)]
+enum Message {
+    AnimationStep,
+}
+
+const INVALID_INPUT_COLOR: enums::Color = enums::Color::from_rgb(255, 200, 200);
+
+fn set_valid(inp: &mut input::FloatInput, valid: bool) {
+    inp.set_color(if valid {
+        enums::Color::Background2
+    } else {
+        INVALID_INPUT_COLOR
+    });
+    inp.redraw();
+}
+
+// Reads the value of the input and checks its domain.
+// Invalid input is highlighted
+fn read_value<C>(inp: &mut input::FloatInput, check: C) -> Result<f64, String>
+where
+    C: Fn(f64) -> Result<(), SolverError>,
+{
+    let text = inp.value();
+    let result = match text.trim().parse::<f64>() {
+        Ok(v) if v.is_finite() => check(v).map(|()| v).map_err(|e| e.to_string()),
+        _ => Err(format!("Not a number: '{text}'")),
+    };
+
+    set_valid(inp, result.is_ok());
+
+    result.map_err(|e| format!("{}: {e}", inp.label().trim_end_matches(" = ")))
+}
+
+// Solutions shown on the graph, `steps` of Newton's method are shown
+// during the animation, all of them when it is `None`
+struct FuncTab {
+    graph: graph_widget::GraphWidget,
+    inp_value: input::FloatInput,
+    inp_start: input::FloatInput,
+    inp_eps: input::FloatInput,
+    check_animate: button::CheckButton,
+    disp: text::TextDisplay,
+    value: f64,
+    traces: Vec<Trace>,
+    steps: Option<usize>,
+    running: bool,
+    tx: app::Sender<Message>,
+}
+
+impl FuncTab {
+    fn set_function(&mut self, function: graph_widget::GraphFunction) {
+        self.graph.set_function(function);
+        self.inp_value.set_label(&format!("{} = ", function.name));
+        self.inp_value
+            .set_value(&format!("{:.4}", function.default));
+        set_valid(&mut self.inp_value, true);
+        self.solve(Ok(function.default));
+    }
+
+    fn set_value(&mut self, value: f64) {
+        self.inp_value.set_value(&format!("{value:.4}"));
+        set_valid(&mut self.inp_value, true);
+        self.solve(Ok(value));
+    }
+
+    fn calculate(&mut self) {
+        let value = read_value(&mut self.inp_value, |_| Ok(()));
+        self.solve(value);
+    }
+
+    fn solve(&mut self, value: Result<f64, String>) {
+        match self.try_solve(value) {
+            Ok(()) => {
+                if self.check_animate.is_checked() {
+                    self.steps = Some(0);
+                    // Animation continues with new solutions when it is running
+                    if !self.running {
+                        self.running = true;
+                        self.tx.send(Message::AnimationStep);
+                    }
+                } else {
+                    self.steps = None;
+                }
+                self.show_solutions();
+            }
+            Err(e) => {
+                self.traces.clear();
+                self.disp.buffer().unwrap().set_text(&format!("{e}\n"));
+                self.graph.clear_lines();
+                self.graph.redraw();
+            }
+        }
+    }
+
+    fn try_solve(&mut self, value: Result<f64, String>) -> Result<(), String> {
+        let lambda_max = flow_func::lambda_max(flow_func::K);
+
+        let value = value?;
+        // Empty start point means the middle of the intervals
+        let start = if self.inp_start.value().trim().is_empty() {
+            set_valid(&mut self.inp_start, true);
+            None
+        } else {
+            Some(read_value(&mut self.inp_start, |x| {
+                check_domain(x, 0.0, lambda_max)
+            })?)
+        };
+        let eps = read_value(&mut self.inp_eps, |e| check_domain(e, EPS_MIN, EPS_MAX))?;
+
+        let func = self.graph.function().func;
+        let params = newton::SearchParams { eps, start };
+        self.traces =
+            newton::solve_all(value, (0.0, lambda_max), &params, |x| func(x, flow_func::K))
+                .map_err(|e| e.to_string())?;
+        self.value = value;
+        Ok(())
+    }
+
+    // Makes the next step of the animation, returns false when all steps are shown
+    fn advance(&mut self) -> bool {
+        let max_steps = self.traces.iter().map(|t| t.path.len() - 1).max();
+        if let (true, Some(steps), Some(n)) = (self.running, self.steps, max_steps)
+            && steps < n
+        {
+            self.steps = Some(steps + 1);
+            self.show_solutions();
+            true
+        } else {
+            self.running = false;
+            false
+        }
+    }
+
+    // Table of approximations with residuals for each solution
+    fn show_solutions(&mut self) {
+        let mut buffer = self.disp.buffer().unwrap();
+        buffer.set_text("");
+
+        let func = self.graph.function().func;
+        for (i, t) in self.traces.iter().enumerate() {
+            let shown = self.steps.map_or(t.path.len(), |n| n + 1);
+            if shown >= t.path.len() {
+                buffer.append(&format!(
+                    "Lambda{}: {:.6}\n{} iterations\n",
+                    i + 1,
+                    t.solution.x,
+                    t.solution.iterations
+                ));
+            } else {
+                buffer.append(&format!("Lambda{}: ...\n\n", i + 1));
+            }
+
+            buffer.append(" n x        residual\n");
+            for (n, &x) in t.path.iter().enumerate().take(shown) {
+                let residual = (func(x, flow_func::K) - self.value).abs();
+                buffer.append(&format!("{n:>2} {x:.6} {residual:.1e}\n"));
+            }
+            buffer.append("\n");
+        }
+
+        self.graph
+            .set_solutions(self.value, &self.traces, self.steps);
+        self.graph.redraw();
+    }
+}
+
+fn make_func_tab(x: i32, y: i32, w: i32, h: i32, tx: app::Sender<Message>) -> Rc<RefCell<FuncTab>> {
+    const INPUT_WIDTH: i32 = 90;
+    const INPUT_HEIGHT: i32 = 25;
+
+    let group = group::Group::default()
+        .with_pos(x, y)
+        .with_size(w, h)
+        .with_label("lambda(func)");
+
+    let graph = graph_widget::GraphWidget::new(x + 10, y + 10, h - 20, h - 20);
+
+    let mut func_choice = menu::Choice::default()
+        .with_size(INPUT_WIDTH, INPUT_HEIGHT)
+        .with_pos(x + w - INPUT_WIDTH - 50, y + 10)
+        .with_label("function ");
+    for f in graph_widget::FUNCTIONS {
+        func_choice.add_choice(&f.name.replace('/', "\\/"));
+    }
+    func_choice.set_value(DEFAULT_FUNCTION as i32);
+    func_choice.set_tooltip("Flow function to solve for lambda");
 
-    let mut graph = graph_widget::GraphWidget::new(10, 10, HEIGHT - 20, HEIGHT - 20);
+    let inp_value = input::FloatInput::default()
+        .with_size(INPUT_WIDTH, INPUT_HEIGHT)
+        .below_of(&func_choice, 5);
 
-    let mut inpq = input::FloatInput::default()
-        .with_size(90, 25)
-        .with_pos(WIDTH - 90 - 50, 10)
-        .with_label("q = ");
+    let mut inp_start = input::FloatInput::default()
+        .with_size(INPUT_WIDTH, INPUT_HEIGHT)
+        .below_of(&inp_value, 5)
+        .with_label("x0 = ");
+    inp_start.set_tooltip("Start point of Newton's method, middle of the intervals if empty");
+
+    let mut inp_eps = input::FloatInput::default()
+        .with_size(INPUT_WIDTH, INPUT_HEIGHT)
+        .below_of(&inp_start, 5)
+        .with_label("eps = ");
+    inp_eps.set_value(&format!("{}", newton::EPS));
+    inp_eps.set_tooltip("Tolerance of the solution");
+
+    let mut check_animate = button::CheckButton::default()
+        .with_size(INPUT_WIDTH, INPUT_HEIGHT)
+        .below_of(&inp_eps, 5)
+        .with_label("Animate");
+    check_animate.set_tooltip("Show steps of Newton's method one by one");
 
     let mut btn_calc = button::Button::default()
-        .with_size(90, 25)
-        .below_of(&inpq, 10)
+        .with_size(INPUT_WIDTH, INPUT_HEIGHT)
+        .below_of(&check_animate, 5)
         .with_label("Calculate");
 
     let buffer = text::TextBuffer::default();
+    let disp_y = btn_calc.y() + btn_calc.h() + 10;
     let mut disp = text::TextDisplay::default()
-        .with_size(190, 200)
-        .with_pos(graph.x() + graph.w() + 10, btn_calc.y() + btn_calc.h() + 10);
+        .with_size(w - graph.w() - 30, y + h - disp_y - 10)
+        .with_pos(graph.x() + graph.w() + 10, disp_y);
     disp.set_buffer(buffer);
+    disp.set_text_font(enums::Font::Courier);
 
-    let mut set_q_value = move |q_val| {
-        let mut buffer = disp.buffer().unwrap();
-        buffer.set_text("");
+    group.end();
+
+    let mut graph_clone = graph.clone();
+    let tab = Rc::from(RefCell::from(FuncTab {
+        graph,
+        inp_value,
+        inp_start,
+        inp_eps,
+        check_animate,
+        disp,
+        value: 0.0,
+        traces: vec![],
+        steps: None,
+        running: false,
+        tx,
+    }));
+
+    // Initial setup of controls
+    tab.borrow_mut()
+        .set_function(graph_widget::FUNCTIONS[DEFAULT_FUNCTION]);
+
+    func_choice.set_callback({
+        let tab = tab.clone();
+        move |c| {
+            if let Some(&function) = graph_widget::FUNCTIONS.get(c.value() as usize) {
+                tab.borrow_mut().set_function(function);
+            }
+        }
+    });
+
+    graph_clone.set_value_callback({
+        let tab = tab.clone();
+        move |value| tab.borrow_mut().set_value(value)
+    });
 
-        let (lambda1, lambda2) = fluid_func::lambda_q(q_val);
+    btn_calc.set_callback({
+        let tab = tab.clone();
+        move |_b| tab.borrow_mut().calculate()
+    });
+
+    tab
+}
 
-        let lambda1_str = format!("Lambda1: {lambda1:.4}\n");
-        buffer.append(&lambda1_str);
+fn make_nozzle_tab(x: i32, y: i32, w: i32, h: i32) {
+    const INPUT_WIDTH: i32 = 90;
+    const INPUT_HEIGHT: i32 = 25;
 
-        let lambda2_str = format!("Lambda2: {lambda2:.4}\n");
-        buffer.append(&lambda2_str);
+    let group = group::Group::default()
+        .with_pos(x, y)
+        .with_size(w, h)
+        .with_label("nozzle");
 
-        let eps_str = format!("Epsilon: {:.6}\n", fluid_func::EPS);
-        buffer.append(&eps_str);
+    let mut nozzle = nozzle_widget::NozzleWidget::new(x + 10, y + 10, h - 20, h - 20);
 
-        graph.set_lines(q_val, lambda1, lambda2);
-        graph.redraw();
+    let make_input = |row: i32, label: &str, value: f64, tooltip: &str| {
+        let mut inp = input::FloatInput::default()
+            .with_size(INPUT_WIDTH, INPUT_HEIGHT)
+            .with_pos(x + w - INPUT_WIDTH - 10, y + 10 + (INPUT_HEIGHT + 5) * row)
+            .with_label(label);
+        inp.set_value(&format!("{value:.4}"));
+        inp.set_tooltip(tooltip);
+        inp
     };
 
-    // Initial setup of controls
-    let q_str = format!("{DEFAULT_Q_VALUE:.4}");
-    inpq.set_value(&q_str);
-    set_q_value(DEFAULT_Q_VALUE);
+    let mut preset_choice = menu::Choice::default()
+        .with_size(INPUT_WIDTH, INPUT_HEIGHT)
+        .with_pos(x + w - INPUT_WIDTH - 10, y + 10)
+        .with_label("profile ");
+    for p in PROFILE_PRESETS {
+        preset_choice.add_choice(p);
+    }
+    preset_choice.set_value(0);
+    preset_choice.set_tooltip("Area distribution of the nozzle");
+
+    let mut inp_inlet = make_input(1, "A_in/A* = ", DEFAULT_INLET_AREA, "Inlet area ratio");
+    let mut inp_exit = make_input(2, "A_e/A* = ", DEFAULT_EXIT_AREA, "Exit area ratio");
+    let mut inp_throat = make_input(3, "x_throat = ", DEFAULT_THROAT_X, "Throat position (0..1)");
+    let mut inp_pb = make_input(
+        4,
+        "pb/p0 = ",
+        DEFAULT_BACK_PRESSURE,
+        "Back pressure related to the inlet total pressure",
+    );
+    let mut inp_k = make_input(5, "k = ", flow_func::K, "Adiabatic index of the gas");
+
+    let mut btn_load = button::Button::default()
+        .with_size(INPUT_WIDTH, INPUT_HEIGHT)
+        .below_of(&inp_k, 10)
+        .with_label("Load...");
+    btn_load.set_tooltip("Load tabulated area distribution (lines with x and A)");
+
+    let mut btn_calc = button::Button::default()
+        .with_size(INPUT_WIDTH, INPUT_HEIGHT)
+        .below_of(&btn_load, 5)
+        .with_label("Calculate");
+
+    let buffer = text::TextBuffer::default();
+    let mut disp = text::TextDisplay::default()
+        .with_size(
+            w - nozzle.w() - 30,
+            y + h - btn_calc.y() - btn_calc.h() - 20,
+        )
+        .with_pos(
+            nozzle.x() + nozzle.w() + 10,
+            btn_calc.y() + btn_calc.h() + 10,
+        );
+    disp.set_buffer(buffer);
+
+    group.end();
+
+    let tabulated: Rc<RefCell<Option<AreaProfile>>> = Rc::from(RefCell::from(None));
 
-    btn_calc.set_callback(move |_b| {
-        let q_val: f64 = inpq.value().parse::<f64>().expect("Not a number!");
+    let mut calculate = {
+        let tabulated = tabulated.clone();
+        let preset_choice = preset_choice.clone();
+        move || {
+            let mut buffer = disp.buffer().unwrap();
+            buffer.set_text("");
 
-        set_q_value(q_val);
+            // All inputs are checked to highlight every invalid one
+            let area_check = |a| check_domain(a, 1.0, f64::INFINITY);
+            let inputs = [
+                read_value(&mut inp_inlet, area_check),
+                read_value(&mut inp_exit, area_check),
+                read_value(&mut inp_throat, |x| {
+                    check_domain(x, THROAT_X_MIN, THROAT_X_MAX)
+                }),
+                read_value(&mut inp_pb, |p| check_domain(p, 0.0, 1.0)),
+                read_value(&mut inp_k, flow_func::check_k),
+            ];
+            let errors: Vec<&String> = inputs.iter().filter_map(|r| r.as_ref().err()).collect();
+            if !errors.is_empty() {
+                for e in errors {
+                    buffer.append(&format!("{e}\n"));
+                }
+                nozzle.clear();
+                nozzle.redraw();
+                return;
+            }
+            let [inlet, exit, throat, pb, k] = inputs.map(Result::unwrap_or_default);
+
+            let profile = match preset_choice.value() {
+                0 => nozzle::conical_profile(inlet, exit, throat),
+                1 => nozzle::bell_profile(inlet, exit, throat),
+                _ => match tabulated.borrow().as_ref() {
+                    Some(p) => p.clone(),
+                    None => {
+                        dialog::alert_default("Load the tabulated profile first");
+                        return;
+                    }
+                },
+            };
+
+            let flow = match nozzle::solve_nozzle(&profile, pb, k) {
+                Ok(flow) => flow,
+                Err(e) => {
+                    buffer.append(&format!("{e}\n"));
+                    nozzle.clear();
+                    nozzle.redraw();
+                    return;
+                }
+            };
+            buffer.append(match flow.regime {
+                NozzleRegime::Subsonic => "Subsonic flow\n",
+                NozzleRegime::ShockInside => "Choked, normal shock\n",
+                NozzleRegime::Supersonic => "Choked, supersonic exit\n",
+            });
+            if let Some(x) = flow.shock_x {
+                buffer.append(&format!("Shock at x: {x:.4}\n"));
+            }
+            buffer.append(&format!("Choked at pb/p0: {:.4}\n", flow.critical.choked));
+            buffer.append(&format!(
+                "Shock at exit at pb/p0: {:.4}\n",
+                flow.critical.shock_at_exit
+            ));
+            buffer.append(&format!("Design pb/p0: {:.4}\n", flow.critical.design));
+            if let Some(&lambda) = flow.lambda.last() {
+                buffer.append(&format!("Exit lambda: {lambda:.4}\n"));
+            }
+
+            nozzle.set_flow(&flow);
+            nozzle.redraw();
+        }
+    };
+
+    calculate();
+
+    btn_load.set_callback({
+        let mut preset_choice = preset_choice.clone();
+        move |_b| {
+            let mut chooser = dialog::NativeFileChooser::new(dialog::FileDialogType::BrowseFile);
+            chooser.set_filter("*.{csv,txt,dat}");
+            chooser.show();
+
+            let path = chooser.filename();
+            if path.as_os_str().is_empty() {
+                return;
+            }
+
+            let profile = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| nozzle::parse_profile(&text));
+            match profile {
+                Ok(p) => {
+                    *tabulated.borrow_mut() = Some(p);
+                    preset_choice.set_value(PRESET_TABULATED);
+                }
+                Err(e) => dialog::alert_default(&format!("Cannot load {}: {e}", path.display())),
+            }
+        }
     });
 
+    btn_calc.set_callback(move |_b| calculate());
+}
+
+fn main() {
+    let a = app::App::default();
+    app::get_system_colors();
+
+    let mut wind = window::Window::default()
+        .with_size(WIDTH, HEIGHT)
+        .with_label("Fluid Flow Visual Calculator");
+    wind.make_resizable(true);
+
+    let tabs = group::Tabs::default()
+        .with_pos(0, 0)
+        .with_size(WIDTH, HEIGHT);
+
+    let (tx, rx) = app::channel::<Message>();
+
+    let func_tab = make_func_tab(0, TABS_HEIGHT, WIDTH, HEIGHT - TABS_HEIGHT, tx);
+    make_nozzle_tab(0, TABS_HEIGHT, WIDTH, HEIGHT - TABS_HEIGHT);
+
+    tabs.end();
+
     if let Some(img) = IconsAssets::get("FluidView32.png") {
         if let Ok(img) = fltk::image::PngImage::from_data(img.data.as_ref()) {
             wind.set_icon(Some(img));
@@ -80,5 +513,19 @@ fn main() {
     wind.end();
     wind.show();
 
-    a.run().unwrap();
+    while a.wait() {
+        if let Some(msg) = rx.recv() {
+            match msg {
+                Message::AnimationStep => {
+                    // Schedule the next step while there are steps to show
+                    if func_tab.borrow_mut().advance() {
+                        thread::spawn(move || {
+                            thread::sleep(Duration::from_millis(ANIMATION_DT));
+                            tx.send(Message::AnimationStep);
+                        });
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,82 @@
+use fltk::{enums, prelude::WidgetExt, widget_extends};
+
+use gas_dynamics::nozzle::NozzleFlow;
+use mechanics_common::plot_area::{Area, Point};
+use mechanics_common::plot_widget::{Guide, PlotData, PlotWidget};
+
+const AREA_COLOR: enums::Color = enums::Color::from_rgb(128, 128, 128);
+const LAMBDA_COLOR: enums::Color = enums::Color::from_rgb(255, 25, 50);
+const PRESSURE_COLOR: enums::Color = enums::Color::from_rgb(0, 64, 192);
+const TEMPERATURE_COLOR: enums::Color = enums::Color::from_rgb(32, 128, 32);
+const SHOCK_COLOR: enums::Color = enums::Color::Black;
+
+pub struct NozzleWidget {
+    inner: PlotWidget,
+}
+
+impl NozzleWidget {
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        let mut inner = PlotWidget::new(x, y, width, height);
+        inner.set_axis_labels("x", "");
+
+        Self { inner }
+    }
+
+    // Plots area ratio A/A*, lambda, p/p0 and T/T0 along the nozzle
+    pub fn set_flow(&mut self, flow: &NozzleFlow) {
+        let make_data = |values: &[f64], color: enums::Color, name: &str| PlotData {
+            points: flow
+                .x
+                .iter()
+                .zip(values)
+                .map(|(&x, &y)| Point { x, y })
+                .collect(),
+            color,
+            name: name.to_string(),
+        };
+
+        let xmin = flow.x.first().copied().unwrap_or(0.0);
+        let xmax = flow.x.last().copied().unwrap_or(1.0);
+        let ymax = flow
+            .area
+            .iter()
+            .chain(&flow.lambda)
+            .fold(1.0_f64, |m, &v| m.max(v));
+        let area = Area {
+            xmin,
+            ymin: 0.0,
+            xmax,
+            ymax: ymax * 1.1,
+        };
+        self.inner.set_area(area);
+
+        self.inner.set_data(vec![
+            make_data(&flow.area, AREA_COLOR, "A/A*"),
+            make_data(&flow.lambda, LAMBDA_COLOR, "lambda"),
+            make_data(&flow.pressure, PRESSURE_COLOR, "p/p0"),
+            make_data(&flow.temperature, TEMPERATURE_COLOR, "T/T0"),
+        ]);
+
+        let guides = flow
+            .shock_x
+            .map(|x| Guide {
+                line: (Point { x, y: area.ymin }, Point { x, y: area.ymax }),
+                color: SHOCK_COLOR,
+            })
+            .into_iter()
+            .collect();
+        self.inner.set_guides(guides);
+    }
+
+    // Removes the plots of the previous flow
+    pub fn clear(&mut self) {
+        self.inner.set_data(vec![]);
+        self.inner.set_guides(vec![]);
+    }
+
+    pub fn redraw(&mut self) {
+        self.inner.redraw();
+    }
+}
+
+widget_extends!(NozzleWidget, PlotWidget, inner);
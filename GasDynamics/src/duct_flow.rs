@@ -0,0 +1,123 @@
+//! Flow in a duct of constant area with friction (Fanno flow) and with heat
+//! addition (Rayleigh flow). Parameters are related to the critical state
+//! (`lambda` = 1) and are expressed as functions of dimensionless velocity
+//! with the same signatures as the isentropic flow functions.
+
+use crate::flow_func::{
+    LambdaFuncResult, lambda_area_ratio, lambda_mach, lambda_phi, lambda_tau, lambda_z, mach, phi,
+    pi, q, tau,
+};
+use crate::newton::{Solution, check_domain};
+
+// Fanno flow
+
+/// Reduced length of the duct to the critical state 4fL*/D
+pub fn fanno_friction(lambda: f64, k: f64) -> f64 {
+    (k + 1.0) / (2.0 * k) * (phi(lambda, k) - 1.0)
+}
+
+/// Static pressure ratio p/p*
+pub fn fanno_pressure(lambda: f64, k: f64) -> f64 {
+    (k + 1.0) / 2.0 * tau(lambda, k) / lambda
+}
+
+/// Static temperature ratio T/T*
+pub fn fanno_temperature(lambda: f64, k: f64) -> f64 {
+    (k + 1.0) / 2.0 * tau(lambda, k)
+}
+
+/// Total pressure ratio p0/p0*
+pub fn fanno_total_pressure(lambda: f64, k: f64) -> f64 {
+    1.0 / q(lambda, k)
+}
+
+/// Subsonic and supersonic dimensionless velocities by reduced length 4fL*/D
+pub fn lambda_fanno_friction(friction: f64, k: f64) -> LambdaFuncResult {
+    // Supersonic flow is limited by the maximal length of the duct
+    check_domain(friction, 0.0, f64::INFINITY)?;
+    lambda_phi(2.0 * k / (k + 1.0) * friction + 1.0, k)
+}
+
+/// Dimensionless velocity by static pressure ratio p/p*
+pub fn lambda_fanno_pressure(pressure: f64, k: f64) -> LambdaFuncResult {
+    check_domain(pressure, 0.0, f64::INFINITY)?;
+    Ok((
+        Solution::exact(
+            ((pressure * pressure + (k + 1.0) * (k - 1.0)).sqrt() - pressure) / (k - 1.0),
+        ),
+        None,
+    ))
+}
+
+/// Dimensionless velocity by static temperature ratio T/T*
+pub fn lambda_fanno_temperature(temperature: f64, k: f64) -> LambdaFuncResult {
+    check_domain(temperature, 0.0, (k + 1.0) / 2.0)?;
+    lambda_tau(2.0 / (k + 1.0) * temperature, k)
+}
+
+/// Subsonic and supersonic dimensionless velocities by total pressure ratio p0/p0*
+pub fn lambda_fanno_total_pressure(total_pressure: f64, k: f64) -> LambdaFuncResult {
+    lambda_area_ratio(total_pressure, k)
+}
+
+// Rayleigh flow
+
+/// Total temperature ratio T0/T0*
+/// (does not depend on adiabatic index)
+pub fn rayleigh_total_temperature(lambda: f64, _k: f64) -> f64 {
+    let z = lambda + 1.0 / lambda;
+    4.0 / (z * z)
+}
+
+/// Static pressure ratio p/p*
+pub fn rayleigh_pressure(lambda: f64, k: f64) -> f64 {
+    let m = mach(lambda, k);
+    (k + 1.0) / (1.0 + k * m * m)
+}
+
+/// Static temperature ratio T/T*
+pub fn rayleigh_temperature(lambda: f64, k: f64) -> f64 {
+    let p = rayleigh_pressure(lambda, k);
+    let m = mach(lambda, k);
+    m * m * p * p
+}
+
+/// Total pressure ratio p0/p0*
+pub fn rayleigh_total_pressure(lambda: f64, k: f64) -> f64 {
+    rayleigh_pressure(lambda, k) * pi(1.0, k) / pi(lambda, k)
+}
+
+/// Subsonic and supersonic dimensionless velocities by total temperature ratio
+/// T0/T0* in (0, 1]
+pub fn lambda_rayleigh_total_temperature(total_temperature: f64, k: f64) -> LambdaFuncResult {
+    check_domain(total_temperature, f64::MIN_POSITIVE, 1.0)?;
+    lambda_z(2.0 / total_temperature.sqrt(), k)
+}
+
+/// Dimensionless velocity by static pressure ratio p/p* in (0, k + 1]
+pub fn lambda_rayleigh_pressure(pressure: f64, k: f64) -> LambdaFuncResult {
+    check_domain(pressure, f64::MIN_POSITIVE, k + 1.0)?;
+    let m2 = ((k + 1.0) / pressure - 1.0) / k;
+    lambda_mach(m2.sqrt(), k)
+}
+
+/// Dimensionless velocities by static temperature ratio T/T* in (0, (k + 1)^2 / 4k].
+/// Solutions are separated by the maximum of temperature at M = 1/sqrt(k)
+pub fn lambda_rayleigh_temperature(temperature: f64, k: f64) -> LambdaFuncResult {
+    check_domain(
+        temperature,
+        f64::MIN_POSITIVE,
+        (k + 1.0).powi(2) / (4.0 * k),
+    )?;
+
+    // Quadratic equation for the square of Mach number
+    let a = temperature * k * k;
+    let b = 2.0 * temperature * k - (k + 1.0).powi(2);
+    let d = (b * b - 4.0 * a * temperature).sqrt();
+    let m2_1 = (-b - d) / (2.0 * a);
+    let m2_2 = (-b + d) / (2.0 * a);
+
+    let (lambda1, _) = lambda_mach(m2_1.sqrt(), k)?;
+    let (lambda2, _) = lambda_mach(m2_2.sqrt(), k)?;
+    Ok((lambda1, Some(lambda2)))
+}
@@ -0,0 +1,258 @@
+use crate::newton::{Solution, SolverError, check_domain, solve_lookup};
+
+/// Default adiabatic index of the gas (air)
+pub const K: f64 = 1.4;
+
+/// Adiabatic indices of common gases
+pub const GAS_PRESETS: [(&str, f64); 5] = [
+    ("Air", 1.4),
+    ("Helium", 1.67),
+    ("Carbon dioxide", 1.29),
+    ("Steam", 1.3),
+    ("Combustion products", 1.33),
+];
+
+/// Maximal value of dimensionless velocity (flow into vacuum)
+pub fn lambda_max(k: f64) -> f64 {
+    ((k + 1.0) / (k - 1.0)).sqrt()
+}
+
+// Direct functions
+
+/// Flow function of dimensionless velocity and adiabatic index
+pub type DirectFunc = fn(f64, f64) -> f64;
+
+/// Temperature ratio T/T0
+pub fn tau(lambda: f64, k: f64) -> f64 {
+    1.0 - lambda * lambda * (k - 1.0) / (k + 1.0)
+}
+
+/// Pressure ratio p/p0
+pub fn pi(lambda: f64, k: f64) -> f64 {
+    tau(lambda, k).powf(k / (k - 1.0))
+}
+
+/// Density ratio rho/rho0
+pub fn eps(lambda: f64, k: f64) -> f64 {
+    tau(lambda, k).powf(1.0 / (k - 1.0))
+}
+
+/// Dimensionless mass flow density (area ratio A*/A)
+pub fn q(lambda: f64, k: f64) -> f64 {
+    eps(lambda, k) * lambda * (2.0 / (k + 1.0)).powf(-1.0 / (k - 1.0))
+}
+
+/// Entropy function phi(lambda) = 1/lambda^2 + 2 ln(lambda)
+/// (does not depend on adiabatic index)
+pub fn phi(lambda: f64, _k: f64) -> f64 {
+    1.0 / (lambda * lambda) + 2.0 * lambda.ln()
+}
+
+/// Mass flow function y = q/pi
+pub fn y(lambda: f64, k: f64) -> f64 {
+    // Simplified form is finite up to the maximal velocity
+    (2.0 / (k + 1.0)).powf(-1.0 / (k - 1.0)) * lambda / tau(lambda, k)
+}
+
+/// Mach number M
+pub fn mach(lambda: f64, k: f64) -> f64 {
+    (2.0 / (k + 1.0) * lambda * lambda / tau(lambda, k)).sqrt()
+}
+
+/// Impulse function z = lambda + 1/lambda
+/// (does not depend on adiabatic index)
+pub fn z(lambda: f64, _k: f64) -> f64 {
+    lambda + 1.0 / lambda
+}
+
+/// Impulse flow function f = (1 + lambda^2) eps
+pub fn f(lambda: f64, k: f64) -> f64 {
+    (1.0 + lambda * lambda) * eps(lambda, k)
+}
+
+/// Impulse pressure function r = pi/f
+pub fn r(lambda: f64, k: f64) -> f64 {
+    pi(lambda, k) / f(lambda, k)
+}
+
+/// Isentropic area ratio A/A* = 1/q
+pub fn area_ratio(lambda: f64, k: f64) -> f64 {
+    1.0 / q(lambda, k)
+}
+
+// Inverse functions
+
+/// Solutions of the inverse function: subsonic (or the only) solution
+/// and optional supersonic solution
+pub type LambdaSolutions = (Solution, Option<Solution>);
+/// Solutions of the inverse function or the reason of failure
+pub type LambdaFuncResult = Result<LambdaSolutions, SolverError>;
+/// Inverse flow function: value of the function and adiabatic index
+/// to dimensionless velocity
+pub type InverseFunc = fn(f64, f64) -> LambdaFuncResult;
+
+/// Check that adiabatic index is inside of the range of real gases
+pub fn check_k(k: f64) -> Result<(), SolverError> {
+    const K_MIN: f64 = 1.01;
+    const K_MAX: f64 = 2.0;
+    check_domain(k, K_MIN, K_MAX)
+}
+
+/// Check that dimensionless velocity is inside of the range [0, lambda_max]
+pub fn check_lambda(lambda: f64, k: f64) -> Result<(), SolverError> {
+    check_domain(lambda, 0.0, lambda_max(k))
+}
+
+fn exact(lambda: f64) -> LambdaFuncResult {
+    Ok((Solution::exact(lambda), None))
+}
+
+/// Dimensionless velocity by temperature ratio
+pub fn lambda_tau(tau: f64, k: f64) -> LambdaFuncResult {
+    check_domain(tau, 0.0, 1.0)?;
+    exact(((k + 1.0) * (1.0 - tau) / (k - 1.0)).sqrt())
+}
+
+/// Dimensionless velocity by pressure ratio
+pub fn lambda_pi(pi: f64, k: f64) -> LambdaFuncResult {
+    check_domain(pi, 0.0, 1.0)?;
+    lambda_tau(pi.powf((k - 1.0) / k), k)
+}
+
+/// Dimensionless velocity by density ratio
+pub fn lambda_eps(eps: f64, k: f64) -> LambdaFuncResult {
+    check_domain(eps, 0.0, 1.0)?;
+    lambda_tau(eps.powf(k - 1.0), k)
+}
+
+// Start points of subsonic and supersonic solutions
+const SUBSONIC_START: f64 = 0.5;
+const SUPERSONIC_START: f64 = 1.5;
+
+// Solves the equation separately on subsonic and supersonic intervals of
+// dimensionless velocity, where the function is monotonic
+fn solve_two_branches<F, DF>(value: f64, k: f64, func: F, dfunc_dx: DF) -> LambdaFuncResult
+where
+    F: Fn(f64) -> f64 + Copy,
+    DF: Fn(f64) -> f64 + Copy,
+{
+    let lambda_max = lambda_max(k);
+    let subsonic = solve_lookup(value, (0.0, 1.0), SUBSONIC_START, func, dfunc_dx);
+    let supersonic = solve_lookup(
+        value,
+        (1.0, lambda_max),
+        SUPERSONIC_START.min(lambda_max),
+        func,
+        dfunc_dx,
+    );
+
+    match (subsonic, supersonic) {
+        (Ok(l1), Ok(l2)) => Ok((l1, Some(l2))),
+        (Ok(l), Err(_)) | (Err(_), Ok(l)) => Ok((l, None)),
+        (Err(e), Err(_)) => Err(e),
+    }
+}
+
+fn dq_dx(lambda: f64, k: f64) -> f64 {
+    let q1 = (2.0 / (k + 1.0)).powf(-1.0 / (k - 1.0));
+    let q2 = tau(lambda, k).powf(1.0 / (k - 1.0) - 1.0);
+    (1.0 - lambda * lambda) * q1 * q2
+}
+
+/// Subsonic and supersonic dimensionless velocities by mass flow density
+pub fn lambda_q(q_c: f64, k: f64) -> LambdaFuncResult {
+    check_domain(q_c, 0.0, 1.0)?;
+    solve_two_branches(q_c, k, |x| q(x, k), |x| dq_dx(x, k))
+}
+
+fn dphi_dx(lambda: f64) -> f64 {
+    2.0 * (1.0 - 1.0 / (lambda * lambda)) / lambda
+}
+
+/// Subsonic and supersonic dimensionless velocities by entropy function.
+/// Supersonic solution exists up to the maximal velocity
+pub fn lambda_phi(phi_c: f64, k: f64) -> LambdaFuncResult {
+    // Subsonic interval starts from small velocity, as the function is infinite at zero
+    const LAMBDA_MIN: f64 = 1e-3;
+
+    check_domain(phi_c, 1.0, phi(LAMBDA_MIN, k))?;
+    let subsonic = solve_lookup(
+        phi_c,
+        (LAMBDA_MIN, 1.0),
+        SUBSONIC_START,
+        |x| phi(x, k),
+        dphi_dx,
+    )?;
+    let supersonic = solve_lookup(
+        phi_c,
+        (1.0, lambda_max(k)),
+        SUPERSONIC_START,
+        |x| phi(x, k),
+        dphi_dx,
+    );
+
+    Ok((subsonic, supersonic.ok()))
+}
+
+fn dy_dx(lambda: f64, k: f64) -> f64 {
+    let a = (k - 1.0) / (k + 1.0);
+    let b = 2.0 / (k + 1.0);
+    let c = b.powf(-1.0 / (k - 1.0));
+    c * (1.0 + a * lambda * lambda) / tau(lambda, k).powi(2)
+}
+
+/// Dimensionless velocity by mass flow function
+pub fn lambda_y(y_c: f64, k: f64) -> LambdaFuncResult {
+    const Y_START: f64 = 1.0;
+    check_domain(y_c, 0.0, f64::INFINITY)?;
+    let lambda = solve_lookup(
+        y_c,
+        (0.0, lambda_max(k)),
+        Y_START,
+        |x| y(x, k),
+        |x| dy_dx(x, k),
+    )?;
+    Ok((lambda, None))
+}
+
+/// Dimensionless velocity by Mach number
+pub fn lambda_mach(mach: f64, k: f64) -> LambdaFuncResult {
+    check_domain(mach, 0.0, f64::INFINITY)?;
+    exact(mach * ((k + 1.0) / 2.0 / (1.0 + (k - 1.0) / 2.0 * mach * mach)).sqrt())
+}
+
+/// Subsonic and supersonic dimensionless velocities by impulse function
+pub fn lambda_z(z_c: f64, k: f64) -> LambdaFuncResult {
+    check_domain(z_c, 2.0, f64::INFINITY)?;
+    let d = (z_c * z_c - 4.0).sqrt();
+    let supersonic = (z_c + d) / 2.0;
+    Ok((
+        Solution::exact((z_c - d) / 2.0),
+        (supersonic <= lambda_max(k)).then(|| Solution::exact(supersonic)),
+    ))
+}
+
+fn df_dx(lambda: f64, k: f64) -> f64 {
+    let t = tau(lambda, k).powf(1.0 / (k - 1.0) - 1.0);
+    2.0 * k / (k + 1.0) * lambda * (1.0 - lambda * lambda) * t
+}
+
+/// Subsonic and supersonic dimensionless velocities by impulse flow function.
+/// Values below f(0) = 1 have only supersonic solution
+pub fn lambda_f(f_c: f64, k: f64) -> LambdaFuncResult {
+    check_domain(f_c, 0.0, f(1.0, k))?;
+    solve_two_branches(f_c, k, |x| f(x, k), |x| df_dx(x, k))
+}
+
+/// Dimensionless velocity by impulse pressure function
+pub fn lambda_r(r: f64, k: f64) -> LambdaFuncResult {
+    check_domain(r, 0.0, 1.0)?;
+    let a = (k - 1.0) / (k + 1.0);
+    exact(((1.0 - r) / (r + a)).sqrt())
+}
+
+/// Subsonic and supersonic dimensionless velocities by area ratio A/A*
+pub fn lambda_area_ratio(area_ratio: f64, k: f64) -> LambdaFuncResult {
+    check_domain(area_ratio, 1.0, f64::INFINITY)?;
+    lambda_q(1.0 / area_ratio, k)
+}
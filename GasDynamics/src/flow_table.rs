@@ -0,0 +1,170 @@
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+
+use std::fmt::Write;
+
+use crate::flow_func::{self, DirectFunc};
+use crate::newton::{SolverError, check_domain};
+
+/// Columns of the table: plain name, name in LaTeX and the flow function
+const COLUMNS: [(&str, &str, DirectFunc); 10] = [
+    ("tau", "$\\tau$", flow_func::tau),
+    ("pi", "$\\pi$", flow_func::pi),
+    ("eps", "$\\varepsilon$", flow_func::eps),
+    ("q", "$q$", flow_func::q),
+    ("phi", "$\\varphi$", flow_func::phi),
+    ("y", "$y$", flow_func::y),
+    ("z", "$z$", flow_func::z),
+    ("f", "$f$", flow_func::f),
+    ("r", "$r$", flow_func::r),
+    ("A/A*", "$A/A^*$", flow_func::area_ratio),
+];
+
+const DIGITS: usize = 4;
+
+/// Smallest step of dimensionless velocity, smaller steps give huge tables
+pub const MIN_STEP: f64 = 1e-4;
+
+/// Output format of the table
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TableFormat {
+    Csv,
+    Markdown,
+    Latex,
+}
+
+impl TableFormat {
+    /// All formats in the order of presentation
+    pub const ALL: [TableFormat; 3] = [TableFormat::Csv, TableFormat::Markdown, TableFormat::Latex];
+
+    /// Short name of the format
+    pub fn name(self) -> &'static str {
+        match self {
+            TableFormat::Csv => "csv",
+            TableFormat::Markdown => "md",
+            TableFormat::Latex => "tex",
+        }
+    }
+
+    /// Format by its short name or file extension
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "csv" => Some(TableFormat::Csv),
+            "md" | "markdown" => Some(TableFormat::Markdown),
+            "tex" | "latex" => Some(TableFormat::Latex),
+            _ => None,
+        }
+    }
+}
+
+/// Range of dimensionless velocity and parameters of the table
+#[derive(Copy, Clone, Debug)]
+pub struct TableParams {
+    pub lambda_min: f64,
+    pub lambda_max: f64,
+    pub step: f64,
+    /// Adiabatic index
+    pub k: f64,
+    /// Add column with Mach number
+    pub mach: bool,
+}
+
+impl Default for TableParams {
+    fn default() -> Self {
+        Self {
+            lambda_min: 0.0,
+            lambda_max: 1.0,
+            step: 0.01,
+            k: flow_func::K,
+            mach: false,
+        }
+    }
+}
+
+impl TableParams {
+    /// Check the adiabatic index, the range of dimensionless velocity inside of
+    /// [0, lambda_max] and the step. Error message names the invalid parameter
+    pub fn validate(&self) -> Result<(), String> {
+        let named = |name: &str, result: Result<(), SolverError>| {
+            result.map_err(|e| format!("{name}: {e}"))
+        };
+
+        named("k", flow_func::check_k(self.k))?;
+        let lambda_limit = flow_func::lambda_max(self.k);
+        named("from", check_domain(self.lambda_min, 0.0, lambda_limit))?;
+        named(
+            "to",
+            check_domain(self.lambda_max, self.lambda_min, lambda_limit),
+        )?;
+        named("step", check_domain(self.step, MIN_STEP, lambda_limit))
+    }
+
+    /// Values of dimensionless velocity in the rows of the table
+    pub fn lambdas(&self) -> Vec<f64> {
+        if self.step <= 0.0 || self.lambda_max < self.lambda_min {
+            return vec![];
+        }
+
+        // Small tolerance keeps the upper bound despite rounding errors
+        let n = ((self.lambda_max - self.lambda_min) / self.step + 1e-9).floor() as usize;
+        (0..=n)
+            .map(|i| self.lambda_min + self.step * (i as f64))
+            .collect()
+    }
+}
+
+fn header(params: &TableParams, latex: bool) -> Vec<&'static str> {
+    let mut names = vec![if latex { "$\\lambda$" } else { "lambda" }];
+    if params.mach {
+        names.push(if latex { "$M$" } else { "M" });
+    }
+    names.extend(COLUMNS.iter().map(|c| if latex { c.1 } else { c.0 }));
+    names
+}
+
+fn row(lambda: f64, params: &TableParams) -> Vec<String> {
+    let mut values = vec![lambda];
+    if params.mach {
+        values.push(flow_func::mach(lambda, params.k));
+    }
+    values.extend(COLUMNS.iter().map(|c| (c.2)(lambda, params.k)));
+    values.iter().map(|v| format!("{v:.DIGITS$}")).collect()
+}
+
+/// Table of all direct flow functions in the given format
+pub fn make_table(params: &TableParams, format: TableFormat) -> String {
+    let mut s = String::new();
+    let header = header(params, format == TableFormat::Latex);
+    let rows: Vec<Vec<String>> = params.lambdas().iter().map(|&l| row(l, params)).collect();
+
+    match format {
+        TableFormat::Csv => {
+            writeln!(s, "{}", header.join(",")).unwrap();
+            for r in &rows {
+                writeln!(s, "{}", r.join(",")).unwrap();
+            }
+        }
+        TableFormat::Markdown => {
+            writeln!(s, "| {} |", header.join(" | ")).unwrap();
+            writeln!(s, "|{}", "---:|".repeat(header.len())).unwrap();
+            for r in &rows {
+                writeln!(s, "| {} |", r.join(" | ")).unwrap();
+            }
+        }
+        TableFormat::Latex => {
+            writeln!(s, "% Gas-dynamic functions, k = {}", params.k).unwrap();
+            writeln!(s, "\\begin{{tabular}}{{{}}}", "r".repeat(header.len())).unwrap();
+            writeln!(s, "\\hline").unwrap();
+            writeln!(s, "{} \\\\", header.join(" & ")).unwrap();
+            writeln!(s, "\\hline").unwrap();
+            for r in &rows {
+                writeln!(s, "{} \\\\", r.join(" & ")).unwrap();
+            }
+            writeln!(s, "\\hline").unwrap();
+            writeln!(s, "\\end{{tabular}}").unwrap();
+        }
+    }
+
+    s
+}
@@ -0,0 +1,11 @@
+//! Gas-dynamic functions of the dimensionless velocity `lambda` of an
+//! isentropic flow, shock wave relations, flow in ducts with friction and
+//! heat addition, quasi-one-dimensional flow in nozzles, tables of the
+//! functions and Newton's method solver used for the inverses.
+
+pub mod duct_flow;
+pub mod flow_func;
+pub mod flow_table;
+pub mod newton;
+pub mod nozzle;
+pub mod shock;
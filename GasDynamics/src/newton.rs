@@ -0,0 +1,315 @@
+#![allow(clippy::cast_precision_loss)]
+
+use std::fmt;
+
+/// Tolerance of the solution
+pub const EPS: f64 = 1e-6;
+/// Maximal number of iterations
+pub const MAX_ITER: i64 = 10_000;
+
+/// Solution of the equation with convergence diagnostics
+#[derive(Copy, Clone, Debug)]
+pub struct Solution {
+    pub x: f64,
+    /// Number of iterations, zero for closed-form solutions
+    pub iterations: i64,
+    /// Absolute difference between the function value at `x` and the lookup value
+    pub residual: f64,
+}
+
+impl Solution {
+    /// Closed-form solution that does not require iterations
+    pub fn exact(x: f64) -> Self {
+        Self {
+            x,
+            iterations: 0,
+            residual: 0.0,
+        }
+    }
+}
+
+/// Solution with the sequence of approximations x0, x1, ... of the method
+#[derive(Clone, Debug)]
+pub struct Trace {
+    pub solution: Solution,
+    pub path: Vec<f64>,
+}
+
+/// Reasons of the solver failure
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SolverError {
+    /// Lookup value is outside of the range of the function
+    OutOfDomain { value: f64, min: f64, max: f64 },
+    /// Iterations did not reach the tolerance
+    NoConvergence { iterations: i64, residual: f64 },
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolverError::OutOfDomain { value, min, max } => {
+                write!(f, "Value {value:.4} is out of range [{min:.4}, {max:.4}]")
+            }
+            SolverError::NoConvergence {
+                iterations,
+                residual,
+            } => write!(
+                f,
+                "No convergence after {iterations} iterations (residual {residual:.2e})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SolverError {}
+
+/// Check that the value is inside of the range [min, max]
+pub fn check_domain(value: f64, min: f64, max: f64) -> Result<(), SolverError> {
+    if value >= min && value <= max {
+        Ok(())
+    } else {
+        Err(SolverError::OutOfDomain { value, min, max })
+    }
+}
+
+/// Newton's method for solving `func(x) = lookup_val` starting from `start_x`
+/// with derivative `dfunc_dx`. The solution is searched inside of the `bracket`
+/// where the function is monotonic. Steps of Newton's method that leave
+/// the bracket are replaced with bisection
+pub fn solve_lookup<F, DF>(
+    lookup_val: f64,
+    bracket: (f64, f64),
+    start_x: f64,
+    func: F,
+    dfunc_dx: DF,
+) -> Result<Solution, SolverError>
+where
+    F: Fn(f64) -> f64,
+    DF: Fn(f64) -> f64,
+{
+    solve(lookup_val, bracket, start_x, EPS, func, dfunc_dx, |_| {})
+}
+
+/// Same as `solve_lookup` with tolerance `eps`, but keeps all approximations of the solution
+pub fn trace_lookup<F, DF>(
+    lookup_val: f64,
+    bracket: (f64, f64),
+    start_x: f64,
+    eps: f64,
+    func: F,
+    dfunc_dx: DF,
+) -> Result<Trace, SolverError>
+where
+    F: Fn(f64) -> f64,
+    DF: Fn(f64) -> f64,
+{
+    let mut path = vec![];
+    let solution = solve(lookup_val, bracket, start_x, eps, func, dfunc_dx, |x| {
+        path.push(x);
+    })?;
+    Ok(Trace { solution, path })
+}
+
+// Safeguarded Newton's method, every approximation is passed to `on_step`
+fn solve<F, DF, S>(
+    lookup_val: f64,
+    bracket: (f64, f64),
+    start_x: f64,
+    eps: f64,
+    func: F,
+    dfunc_dx: DF,
+    mut on_step: S,
+) -> Result<Solution, SolverError>
+where
+    F: Fn(f64) -> f64,
+    DF: Fn(f64) -> f64,
+    S: FnMut(f64),
+{
+    let g = |x: f64| func(x) - lookup_val;
+
+    let (mut a, mut b) = bracket;
+    let (ga, gb) = (g(a), g(b));
+
+    if ga == 0.0 {
+        on_step(a);
+        return Ok(Solution::exact(a));
+    }
+    if gb == 0.0 {
+        on_step(b);
+        return Ok(Solution::exact(b));
+    }
+    if ga.signum() == gb.signum() || ga.is_nan() || gb.is_nan() {
+        let (fa, fb) = (func(a), func(b));
+        return Err(SolverError::OutOfDomain {
+            value: lookup_val,
+            min: fa.min(fb),
+            max: fa.max(fb),
+        });
+    }
+
+    let mut xn = start_x.clamp(a, b);
+    on_step(xn);
+
+    for i in 1..=MAX_ITER {
+        let old_xn = xn;
+        let gx = g(old_xn);
+        if gx == 0.0 {
+            return Ok(Solution {
+                x: old_xn,
+                iterations: i,
+                residual: 0.0,
+            });
+        }
+
+        // Keep the solution inside of the bracket
+        if gx.signum() == ga.signum() {
+            a = old_xn;
+        } else {
+            b = old_xn;
+        }
+
+        xn = old_xn - gx / dfunc_dx(old_xn);
+        if !xn.is_finite() || xn <= a || xn >= b {
+            xn = 0.5 * (a + b);
+        }
+        on_step(xn);
+
+        if (xn - old_xn).abs() < eps || (b - a) < eps {
+            return Ok(Solution {
+                x: xn,
+                iterations: i,
+                residual: g(xn).abs(),
+            });
+        }
+    }
+
+    Err(SolverError::NoConvergence {
+        iterations: MAX_ITER,
+        residual: g(xn).abs(),
+    })
+}
+
+// Step of numerical differentiation
+const DIFF_STEP: f64 = 1e-7;
+// Number of intervals for search of the extrema of the function
+const SEARCH_INTERVALS: usize = 200;
+
+/// Central difference approximation of the derivative
+pub fn derivative<F: Fn(f64) -> f64>(func: F, x: f64) -> f64 {
+    (func(x + DIFF_STEP) - func(x - DIFF_STEP)) / (2.0 * DIFF_STEP)
+}
+
+/// Split the `range` into intervals where the function is monotonic.
+/// Extrema are found by sampling and refined by bisection of the sign of the derivative
+pub fn monotonic_intervals<F: Fn(f64) -> f64>(range: (f64, f64), func: F) -> Vec<(f64, f64)> {
+    let (a, b) = range;
+    let x_at = |i: usize| a + (b - a) * (i as f64) / (SEARCH_INTERVALS as f64);
+    let slope = |i: usize| (func(x_at(i + 1)) - func(x_at(i))).signum();
+
+    let mut bounds = vec![a];
+    for i in 1..SEARCH_INTERVALS {
+        // Infinite values at the ends of the range are not extrema
+        if slope(i - 1) == slope(i) || slope(i - 1).is_nan() || slope(i).is_nan() {
+            continue;
+        }
+
+        // Extremum is between the samples i-1 and i+1
+        let (mut x1, mut x2) = (x_at(i - 1), x_at(i + 1));
+        let s1 = slope(i - 1);
+        while x2 - x1 > EPS {
+            let xm = 0.5 * (x1 + x2);
+            if derivative(&func, xm).signum() == s1 {
+                x1 = xm;
+            } else {
+                x2 = xm;
+            }
+        }
+        bounds.push(0.5 * (x1 + x2));
+    }
+    bounds.push(b);
+
+    bounds.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+/// Parameters of the search of all solutions
+#[derive(Copy, Clone, Debug)]
+pub struct SearchParams {
+    /// Tolerance of the solutions
+    pub eps: f64,
+    /// Start point of the interval that contains it.
+    /// Other intervals (or all of them when not set) start at the middle
+    pub start: Option<f64>,
+}
+
+impl Default for SearchParams {
+    fn default() -> Self {
+        Self {
+            eps: EPS,
+            start: None,
+        }
+    }
+}
+
+/// All solutions of `func(x) = lookup_val` inside of the `range`, one for each
+/// interval of monotonicity. Newton's method uses numerical derivative
+pub fn solve_all<F: Fn(f64) -> f64>(
+    lookup_val: f64,
+    range: (f64, f64),
+    params: &SearchParams,
+    func: F,
+) -> Result<Vec<Trace>, SolverError> {
+    let mut traces = vec![];
+    let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+
+    // Ends of the range are moved inside where the function is singular
+    let inside = |x: f64, dx: f64| if func(x).is_finite() { x } else { x + dx };
+    let range = (inside(range.0, EPS), inside(range.1, -EPS));
+
+    for (a, b) in monotonic_intervals(range, &func) {
+        let start = match params.start {
+            Some(x) if x >= a && x <= b => x,
+            _ => 0.5 * (a + b),
+        };
+        match trace_lookup(lookup_val, (a, b), start, params.eps, &func, |x| {
+            derivative(&func, x)
+        }) {
+            Ok(trace) => traces.push(trace),
+            Err(SolverError::OutOfDomain {
+                min: fmin,
+                max: fmax,
+                ..
+            }) => {
+                min = min.min(fmin);
+                max = max.max(fmax);
+
+                // Value at the extremum is found only approximately
+                for x in [a, b] {
+                    let residual = (func(x) - lookup_val).abs();
+                    if residual < params.eps
+                        && traces.iter().all(|t| (t.solution.x - x).abs() > params.eps)
+                    {
+                        traces.push(Trace {
+                            solution: Solution {
+                                x,
+                                iterations: 0,
+                                residual,
+                            },
+                            path: vec![x],
+                        });
+                    }
+                }
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    if traces.is_empty() {
+        Err(SolverError::OutOfDomain {
+            value: lookup_val,
+            min,
+            max,
+        })
+    } else {
+        Ok(traces)
+    }
+}
@@ -0,0 +1,250 @@
+#![allow(clippy::cast_precision_loss)]
+
+use std::f64::consts::PI;
+
+use crate::flow_func::{lambda_pi, lambda_q, lambda_y, pi, q, tau};
+use crate::newton::{SolverError, check_domain};
+use crate::shock::normal_shock;
+
+/// Area distribution along the nozzle: pairs of coordinate x and area A(x)
+pub type AreaProfile = Vec<(f64, f64)>;
+
+/// Number of points of the preset profiles
+pub const PROFILE_POINTS: usize = 200;
+
+// Preset profile with the throat of unit radius at `throat_x` on the interval [0, 1].
+// Radius functions map local coordinate [0, 1] of convergent and divergent parts
+fn preset_profile(
+    inlet_area: f64,
+    exit_area: f64,
+    throat_x: f64,
+    convergent: fn(f64) -> f64,
+    divergent: fn(f64) -> f64,
+) -> AreaProfile {
+    let r_in = inlet_area.sqrt();
+    let r_exit = exit_area.sqrt();
+
+    (0..PROFILE_POINTS)
+        .map(|i| {
+            let x = (i as f64) / ((PROFILE_POINTS - 1) as f64);
+            let r = if x < throat_x {
+                1.0 + (r_in - 1.0) * convergent(x / throat_x)
+            } else {
+                1.0 + (r_exit - 1.0) * divergent((x - throat_x) / (1.0 - throat_x))
+            };
+            (x, r * r)
+        })
+        .collect()
+}
+
+/// Conical nozzle with linear radius of both parts. Areas are related to the throat area
+pub fn conical_profile(inlet_area: f64, exit_area: f64, throat_x: f64) -> AreaProfile {
+    preset_profile(inlet_area, exit_area, throat_x, |s| 1.0 - s, |s| s)
+}
+
+/// Bell nozzle with smooth throat and parallel flow at the exit.
+/// Areas are related to the throat area
+pub fn bell_profile(inlet_area: f64, exit_area: f64, throat_x: f64) -> AreaProfile {
+    preset_profile(
+        inlet_area,
+        exit_area,
+        throat_x,
+        |s| (1.0 + (PI * s).cos()) / 2.0,
+        |s| s * (2.0 - s),
+    )
+}
+
+/// Read tabulated profile: lines with coordinate and area separated
+/// by comma, semicolon or whitespace. Empty lines and lines starting with `#` are skipped
+pub fn parse_profile(text: &str) -> Result<AreaProfile, String> {
+    let mut profile = AreaProfile::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let values: Vec<f64> = line
+            .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(str::parse::<f64>)
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("Line {}: not a number", i + 1))?;
+
+        match values[..] {
+            [x, a] if a > 0.0 => profile.push((x, a)),
+            [_, _] => return Err(format!("Line {}: area must be positive", i + 1)),
+            _ => return Err(format!("Line {}: expected two values", i + 1)),
+        }
+    }
+
+    if profile.len() < 2 {
+        return Err("Profile must contain at least two points".to_string());
+    }
+    if profile.windows(2).any(|w| w[1].0 <= w[0].0) {
+        return Err("Coordinates must increase along the nozzle".to_string());
+    }
+
+    Ok(profile)
+}
+
+/// Flow regime in the nozzle
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum NozzleRegime {
+    /// Subsonic flow everywhere, the throat is not choked
+    Subsonic,
+    /// Choked flow with a normal shock in the divergent part
+    ShockInside,
+    /// Choked flow with supersonic divergent part
+    Supersonic,
+}
+
+/// Back pressures p/p0 that separate flow regimes
+#[derive(Copy, Clone, Debug)]
+pub struct CriticalPressures {
+    /// Sonic throat with subsonic divergent part
+    pub choked: f64,
+    /// Normal shock at the exit
+    pub shock_at_exit: f64,
+    /// Supersonic isentropic flow without shocks
+    pub design: f64,
+}
+
+/// Parameters of the flow along the nozzle. Pressure and temperature are
+/// related to the total values at the inlet, areas to the throat area
+pub struct NozzleFlow {
+    pub x: Vec<f64>,
+    pub area: Vec<f64>,
+    pub lambda: Vec<f64>,
+    pub pressure: Vec<f64>,
+    pub temperature: Vec<f64>,
+    pub regime: NozzleRegime,
+    pub critical: CriticalPressures,
+    /// Coordinate of the normal shock
+    pub shock_x: Option<f64>,
+}
+
+impl NozzleFlow {
+    fn push(&mut self, x: f64, area: f64, lambda: f64, sigma: f64, k: f64) {
+        self.x.push(x);
+        self.area.push(area);
+        self.lambda.push(lambda);
+        self.pressure.push(sigma * pi(lambda, k));
+        self.temperature.push(tau(lambda, k));
+    }
+}
+
+/// Quasi-one-dimensional isentropic flow in the nozzle with normal shock
+/// by the back pressure related to the inlet total pressure `back_pressure` = pb/p0
+pub fn solve_nozzle(
+    profile: &AreaProfile,
+    back_pressure: f64,
+    k: f64,
+) -> Result<NozzleFlow, SolverError> {
+    check_domain(back_pressure, 0.0, 1.0)?;
+
+    // Subsonic and supersonic solutions by area ratio A/A*. Ratio is limited
+    // by the critical area to avoid rounding errors near the throat
+    let subsonic = |a: f64| lambda_q((1.0 / a).min(1.0), k).map(|l| l.0.x);
+    let supersonic = |a: f64| lambda_q((1.0 / a).min(1.0), k).map(|l| l.1.unwrap_or(l.0).x);
+
+    // Throat is the section of minimal area
+    let (throat, throat_area) = profile
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i, p.1))
+        .fold((0, f64::MAX), |m, p| if p.1 < m.1 { p } else { m });
+    let areas: Vec<(f64, f64)> = profile.iter().map(|p| (p.0, p.1 / throat_area)).collect();
+
+    let exit_area = areas[areas.len() - 1].1;
+    let lambda_sub = subsonic(exit_area)?;
+    let lambda_sup = supersonic(exit_area)?;
+    let critical = CriticalPressures {
+        choked: pi(lambda_sub, k),
+        shock_at_exit: pi(lambda_sup, k) * normal_shock(lambda_sup, k).pressure_ratio,
+        design: pi(lambda_sup, k),
+    };
+
+    let mut flow = NozzleFlow {
+        x: vec![],
+        area: vec![],
+        lambda: vec![],
+        pressure: vec![],
+        temperature: vec![],
+        regime: NozzleRegime::Supersonic,
+        critical,
+        shock_x: None,
+    };
+
+    if back_pressure >= critical.choked {
+        // Critical area is larger than the throat, subsonic solution everywhere
+        flow.regime = NozzleRegime::Subsonic;
+        let lambda_exit = lambda_pi(back_pressure, k)?.0.x;
+        let critical_area = q(lambda_exit, k) * exit_area;
+        for &(x, a) in &areas {
+            flow.push(x, a, subsonic(a / critical_area)?, 1.0, k);
+        }
+        return Ok(flow);
+    }
+
+    // Choked flow: subsonic before the throat and supersonic after it
+    let choked_lambda = |i: usize, a: f64| {
+        if i <= throat {
+            subsonic(a)
+        } else {
+            supersonic(a)
+        }
+    };
+
+    if back_pressure < critical.shock_at_exit {
+        for (i, &(x, a)) in areas.iter().enumerate() {
+            flow.push(x, a, choked_lambda(i, a)?, 1.0, k);
+        }
+        return Ok(flow);
+    }
+
+    // Total pressure recovery is found by the subsonic exit flow:
+    // pb * Ae / (p0 * At) = 1 / y(lambda_exit)
+    flow.regime = NozzleRegime::ShockInside;
+    let lambda_exit = lambda_y(1.0 / (back_pressure * exit_area), k)?.0.x;
+    let sigma = 1.0 / (q(lambda_exit, k) * exit_area);
+
+    // Shock is located where the recovery of supersonic flow equals the required value
+    let mut shocked = false;
+    for (i, &(x, a)) in areas.iter().enumerate() {
+        let lambda = choked_lambda(i, a)?;
+
+        if !shocked && i > throat {
+            let (x0, a0) = areas[i - 1];
+            let sigma0 = normal_shock(choked_lambda(i - 1, a0)?, k).sigma;
+            let sigma1 = normal_shock(lambda, k).sigma;
+
+            if sigma1 <= sigma {
+                let t = if sigma0 > sigma1 {
+                    ((sigma0 - sigma) / (sigma0 - sigma1)).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+                let shock_x = x0 + (x - x0) * t;
+                let shock_area = a0 + (a - a0) * t;
+                let shock_lambda = supersonic(shock_area)?;
+
+                // Both sides of the shock are in the same section
+                flow.push(shock_x, shock_area, shock_lambda, 1.0, k);
+                flow.push(shock_x, shock_area, 1.0 / shock_lambda, sigma, k);
+
+                flow.shock_x = Some(shock_x);
+                shocked = true;
+            }
+        }
+
+        if shocked {
+            flow.push(x, a, subsonic(sigma * a)?, sigma, k);
+        } else {
+            flow.push(x, a, lambda, 1.0, k);
+        }
+    }
+
+    Ok(flow)
+}
@@ -0,0 +1,174 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::flow_func::{LambdaFuncResult, lambda_mach, mach, q, tau};
+use crate::newton::{Solution, check_domain, solve_lookup};
+
+/// Parameters of the flow after a normal shock wave
+#[derive(Copy, Clone, Debug)]
+pub struct NormalShock {
+    /// Upstream dimensionless velocity
+    pub lambda1: f64,
+    /// Downstream dimensionless velocity
+    pub lambda2: f64,
+    /// Upstream Mach number
+    pub mach1: f64,
+    /// Downstream Mach number
+    pub mach2: f64,
+    /// Static pressure ratio p2/p1
+    pub pressure_ratio: f64,
+    /// Static temperature ratio T2/T1
+    pub temperature_ratio: f64,
+    /// Density ratio rho2/rho1
+    pub density_ratio: f64,
+    /// Total pressure recovery p02/p01
+    pub sigma: f64,
+    /// Entropy change related to the gas constant (s2 - s1)/R
+    pub entropy_change: f64,
+}
+
+/// Normal shock relations by upstream dimensionless velocity `lambda1` > 1
+pub fn normal_shock(lambda1: f64, k: f64) -> NormalShock {
+    let lambda2 = 1.0 / lambda1;
+    let mach1 = mach(lambda1, k);
+
+    // Total temperature is conserved, so the mass flow gives the total pressure ratio
+    let sigma = q(lambda1, k) / q(lambda2, k);
+
+    NormalShock {
+        lambda1,
+        lambda2,
+        mach1,
+        mach2: mach(lambda2, k),
+        pressure_ratio: (2.0 * k * mach1 * mach1 - (k - 1.0)) / (k + 1.0),
+        temperature_ratio: tau(lambda2, k) / tau(lambda1, k),
+        density_ratio: lambda1 * lambda1,
+        sigma,
+        entropy_change: -sigma.ln(),
+    }
+}
+
+/// Parameters of the flow after an oblique shock wave
+#[derive(Copy, Clone, Debug)]
+pub struct ObliqueShock {
+    /// Shock wave angle beta (radians)
+    pub beta: f64,
+    /// Flow deflection angle theta (radians)
+    pub theta: f64,
+    /// Downstream Mach number
+    pub mach2: f64,
+    /// Normal shock relations for the velocity component normal to the wave
+    pub normal: NormalShock,
+}
+
+/// Flow deflection angle theta by upstream Mach number and shock wave angle beta
+/// (theta-beta-M relation, angles in radians)
+pub fn deflection_angle(mach1: f64, beta: f64, k: f64) -> f64 {
+    let m2 = mach1 * mach1;
+    let sb = beta.sin();
+    (2.0 / beta.tan() * (m2 * sb * sb - 1.0) / (m2 * (k + (2.0 * beta).cos()) + 2.0)).atan()
+}
+
+/// Shock wave angle of the maximal flow deflection (detachment)
+pub fn detachment_beta(mach1: f64, k: f64) -> f64 {
+    let m2 = mach1 * mach1;
+    let d = ((k + 1.0) * (1.0 + (k - 1.0) / 2.0 * m2 + (k + 1.0) / 16.0 * m2 * m2)).sqrt();
+    (((k + 1.0) / 4.0 * m2 - 1.0 + d) / (k * m2)).sqrt().asin()
+}
+
+/// Maximal flow deflection angle for the attached oblique shock (radians)
+pub fn detachment_angle(mach1: f64, k: f64) -> f64 {
+    deflection_angle(mach1, detachment_beta(mach1, k), k)
+}
+
+/// Weak and strong shock wave angles by upstream Mach number and flow
+/// deflection angle theta (radians). Deflection angle is limited by
+/// the detachment angle
+pub fn shock_angle(mach1: f64, theta: f64, k: f64) -> LambdaFuncResult {
+    check_domain(mach1, 1.0, f64::INFINITY)?;
+    check_domain(theta, 0.0, detachment_angle(mach1, k))?;
+
+    if theta == 0.0 {
+        // Mach wave and normal shock
+        return Ok((
+            Solution::exact((1.0 / mach1).asin()),
+            Some(Solution::exact(FRAC_PI_2)),
+        ));
+    }
+
+    // Explicit solution of the cubic equation for tan(beta)
+    let m2 = mach1 * mach1;
+    let t = theta.tan();
+    let a = 1.0 + (k - 1.0) / 2.0 * m2;
+    let b = 1.0 + (k + 1.0) / 2.0 * m2;
+    let l = ((m2 - 1.0).powi(2) - 3.0 * a * b * t * t).sqrt();
+    // Rounding at the detachment angle may put the cosine slightly out of [-1, 1]
+    let chi = (((m2 - 1.0).powi(3) - 9.0 * a * (a + (k + 1.0) / 4.0 * m2 * m2) * t * t)
+        / l.powi(3))
+    .clamp(-1.0, 1.0);
+    let beta = |delta: f64| {
+        ((m2 - 1.0 + 2.0 * l * ((4.0 * PI * delta + chi.acos()) / 3.0).cos()) / (3.0 * a * t))
+            .atan()
+    };
+
+    Ok((Solution::exact(beta(1.0)), Some(Solution::exact(beta(0.0)))))
+}
+
+/// Oblique shock relations by upstream Mach number and shock wave angle beta (radians)
+pub fn oblique_shock(mach1: f64, beta: f64, k: f64) -> ObliqueShock {
+    let theta = deflection_angle(mach1, beta, k);
+    let lambda_n = lambda_mach(mach1 * beta.sin(), k).map_or(f64::NAN, |l| l.0.x);
+    let normal = normal_shock(lambda_n, k);
+
+    ObliqueShock {
+        beta,
+        theta,
+        mach2: normal.mach2 / (beta - theta).sin(),
+        normal,
+    }
+}
+
+/// Mach angle mu (radians)
+pub fn mach_angle(mach: f64) -> f64 {
+    (1.0 / mach).asin()
+}
+
+/// Prandtl-Meyer function nu(M) (radians)
+pub fn prandtl_meyer(mach: f64, k: f64) -> f64 {
+    prandtl_meyer_m((mach * mach - 1.0).sqrt(), k)
+}
+
+// Prandtl-Meyer function of m = sqrt(M^2 - 1)
+fn prandtl_meyer_m(m: f64, k: f64) -> f64 {
+    let c = ((k + 1.0) / (k - 1.0)).sqrt();
+    c * (m / c).atan() - m.atan()
+}
+
+/// Maximal value of Prandtl-Meyer function (expansion into vacuum, radians)
+pub fn prandtl_meyer_max(k: f64) -> f64 {
+    (((k + 1.0) / (k - 1.0)).sqrt() - 1.0) * FRAC_PI_2
+}
+
+fn dprandtl_meyer_dm(m: f64, k: f64) -> f64 {
+    let c = (k + 1.0) / (k - 1.0);
+    m * m * (1.0 - 1.0 / c) / ((1.0 + m * m / c) * (1.0 + m * m))
+}
+
+/// Mach number by value of Prandtl-Meyer function (radians)
+pub fn mach_prandtl_meyer(nu: f64, k: f64) -> LambdaFuncResult {
+    // Cubic root of the function is concave in m = sqrt(M^2 - 1), so the
+    // iterations starting from the left of the solution approach it monotonically
+    const M_START: f64 = 0.1;
+    const M_MAX: f64 = 1e4;
+    let cbrt_nu = |m: f64| prandtl_meyer_m(m, k).cbrt();
+    let dcbrt_nu = |m: f64| dprandtl_meyer_dm(m, k) / (3.0 * cbrt_nu(m).powi(2));
+
+    check_domain(nu, 0.0, prandtl_meyer_m(M_MAX, k))?;
+    let m = solve_lookup(nu.cbrt(), (0.0, M_MAX), M_START, cbrt_nu, dcbrt_nu)?;
+    Ok((
+        Solution {
+            x: (1.0 + m.x * m.x).sqrt(),
+            ..m
+        },
+        None,
+    ))
+}
@@ -0,0 +1,64 @@
+use gas_dynamics::duct_flow;
+use gas_dynamics::flow_func::{self, K};
+use gas_dynamics::newton::SolverError;
+
+const TOLERANCE: f64 = 1e-4;
+
+fn assert_close(name: &str, value: f64, expected: f64) {
+    assert!(
+        (value - expected).abs() < TOLERANCE,
+        "{name} = {value}, expected {expected}"
+    );
+}
+
+fn lambda_of_mach(mach: f64) -> f64 {
+    flow_func::lambda_mach(mach, K).unwrap().0.x
+}
+
+#[test]
+fn fanno_mach_2() {
+    let lambda = lambda_of_mach(2.0);
+    assert_close("4fL*/D", duct_flow::fanno_friction(lambda, K), 0.3050);
+    assert_close("p/p*", duct_flow::fanno_pressure(lambda, K), 0.4082);
+    assert_close("T/T*", duct_flow::fanno_temperature(lambda, K), 0.6667);
+    assert_close("p0/p0*", duct_flow::fanno_total_pressure(lambda, K), 1.6875);
+
+    let (_, supersonic) = duct_flow::lambda_fanno_friction(0.3050, K).unwrap();
+    assert_close("lambda", supersonic.unwrap().x, lambda);
+}
+
+#[test]
+fn rayleigh_mach_2() {
+    let lambda = lambda_of_mach(2.0);
+    assert_close(
+        "T0/T0*",
+        duct_flow::rayleigh_total_temperature(lambda, K),
+        0.7934,
+    );
+    assert_close("p/p*", duct_flow::rayleigh_pressure(lambda, K), 0.3636);
+    assert_close("T/T*", duct_flow::rayleigh_temperature(lambda, K), 0.5289);
+    assert_close(
+        "p0/p0*",
+        duct_flow::rayleigh_total_pressure(lambda, K),
+        1.5031,
+    );
+
+    let (l, _) =
+        duct_flow::lambda_rayleigh_pressure(duct_flow::rayleigh_pressure(lambda, K), K).unwrap();
+    assert_close("lambda", l.x, lambda);
+}
+
+#[test]
+fn rayleigh_zero_ratios() {
+    // Error reports the input instead of the result of 0/0 in the solution
+    for inverse in [
+        duct_flow::lambda_rayleigh_total_temperature,
+        duct_flow::lambda_rayleigh_pressure,
+        duct_flow::lambda_rayleigh_temperature,
+    ] {
+        assert!(matches!(
+            inverse(0.0, K),
+            Err(SolverError::OutOfDomain { value, .. }) if value == 0.0
+        ));
+    }
+}
@@ -0,0 +1,76 @@
+use gas_dynamics::flow_func::{self, DirectFunc, InverseFunc, K};
+use gas_dynamics::newton::SolverError;
+
+// Solutions are limited by the tolerance of Newton's method
+const TOLERANCE: f64 = 1e-6;
+
+const SUBSONIC: [f64; 4] = [0.1, 0.4, 0.7, 0.95];
+const SUPERSONIC: [f64; 4] = [1.05, 1.4, 1.8, 2.2];
+
+// Inverse of the direct function gives back the velocity: the first solution
+// below the critical velocity and the supersonic (or the only) solution above it
+fn check_round_trip(name: &str, direct: DirectFunc, inverse: InverseFunc, lambdas: &[f64]) {
+    for &lambda in lambdas {
+        let value = direct(lambda, K);
+        let (first, second) =
+            inverse(value, K).unwrap_or_else(|e| panic!("{name}({lambda}) = {value}: {e}"));
+        let x = if lambda > 1.0 {
+            second.unwrap_or(first).x
+        } else {
+            first.x
+        };
+        assert!(
+            (x - lambda).abs() < TOLERANCE,
+            "{name}({lambda}) = {value}: solution {x}"
+        );
+    }
+}
+
+#[test]
+fn inverse_round_trips() {
+    let both: Vec<f64> = SUBSONIC.iter().chain(&SUPERSONIC).copied().collect();
+    check_round_trip("q", flow_func::q, flow_func::lambda_q, &both);
+    check_round_trip("phi", flow_func::phi, flow_func::lambda_phi, &both);
+    check_round_trip("y", flow_func::y, flow_func::lambda_y, &both);
+    check_round_trip("f", flow_func::f, flow_func::lambda_f, &both);
+}
+
+#[test]
+fn two_branches() {
+    // Mass flow density of 0.5 is reached in subsonic and supersonic flow
+    let (subsonic, supersonic) = flow_func::lambda_q(0.5, K).unwrap();
+    let supersonic = supersonic.expect("supersonic solution of q");
+    assert!(subsonic.x < 1.0 && supersonic.x > 1.0);
+    assert!((flow_func::q(subsonic.x, K) - 0.5).abs() < TOLERANCE);
+    assert!((flow_func::q(supersonic.x, K) - 0.5).abs() < TOLERANCE);
+
+    // Impulse flow function below f(0) = 1 has only supersonic solution
+    let (only, none) = flow_func::lambda_f(0.5, K).unwrap();
+    assert!(only.x > 1.0 && none.is_none());
+}
+
+#[test]
+fn out_of_domain() {
+    assert_eq!(
+        flow_func::lambda_q(1.5, K).unwrap_err(),
+        SolverError::OutOfDomain {
+            value: 1.5,
+            min: 0.0,
+            max: 1.0
+        }
+    );
+    assert!(matches!(
+        flow_func::lambda_tau(-0.1, K),
+        Err(SolverError::OutOfDomain { value, .. }) if value == -0.1
+    ));
+    assert!(matches!(
+        flow_func::lambda_phi(0.5, K),
+        Err(SolverError::OutOfDomain { value, .. }) if value == 0.5
+    ));
+    assert!(matches!(
+        flow_func::lambda_y(-1.0, K),
+        Err(SolverError::OutOfDomain { value, .. }) if value == -1.0
+    ));
+    assert!(flow_func::lambda_f(f64::NAN, K).is_err());
+    assert!(flow_func::check_k(1.0).is_err());
+}
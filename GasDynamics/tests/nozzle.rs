@@ -0,0 +1,62 @@
+use gas_dynamics::flow_func::K;
+use gas_dynamics::nozzle::{self, NozzleRegime};
+
+const POINTS: i32 = 100;
+const THROAT_X: f64 = 0.4;
+
+// Linear areas from A/A* = 3 at the inlet to 2 at the exit, the throat is one of the points
+fn profile() -> nozzle::AreaProfile {
+    (0..=POINTS)
+        .map(|i| {
+            let x = f64::from(i) / f64::from(POINTS);
+            let area = if x < THROAT_X {
+                3.0 - 2.0 * x / THROAT_X
+            } else {
+                1.0 + (x - THROAT_X) / (1.0 - THROAT_X)
+            };
+            (x, area)
+        })
+        .collect()
+}
+
+#[test]
+fn critical_pressures() {
+    let flow = nozzle::solve_nozzle(&profile(), 0.5, K).unwrap();
+    let c = flow.critical;
+    // Isentropic pressure ratios of the subsonic and supersonic flow at A/A* = 2
+    assert!((c.choked - 0.9372).abs() < 1e-4, "choked {}", c.choked);
+    assert!((c.design - 0.0939).abs() < 1e-4, "design {}", c.design);
+    assert!(c.design < c.shock_at_exit && c.shock_at_exit < c.choked);
+}
+
+#[test]
+fn nozzle_regimes() {
+    let c = nozzle::solve_nozzle(&profile(), 0.5, K).unwrap().critical;
+    let regime = |back_pressure: f64| nozzle::solve_nozzle(&profile(), back_pressure, K).unwrap();
+
+    let subsonic = regime(c.choked + 0.01);
+    assert_eq!(subsonic.regime, NozzleRegime::Subsonic);
+    assert!(subsonic.lambda.iter().all(|&l| l < 1.0));
+
+    // Shock moves from the throat to the exit as the back pressure decreases
+    let near_throat = regime(c.choked - 0.01);
+    let near_exit = regime(c.shock_at_exit + 0.01);
+    assert_eq!(near_throat.regime, NozzleRegime::ShockInside);
+    assert_eq!(near_exit.regime, NozzleRegime::ShockInside);
+    let (x1, x2) = (near_throat.shock_x.unwrap(), near_exit.shock_x.unwrap());
+    assert!(
+        THROAT_X < x1 && x1 < x2 && x2 < 1.0,
+        "shock at {x1} and {x2}"
+    );
+
+    let supersonic = regime(c.shock_at_exit - 0.01);
+    assert_eq!(supersonic.regime, NozzleRegime::Supersonic);
+    assert!(supersonic.shock_x.is_none());
+    assert!(*supersonic.lambda.last().unwrap() > 1.0);
+}
+
+#[test]
+fn back_pressure_out_of_domain() {
+    assert!(nozzle::solve_nozzle(&profile(), 1.5, K).is_err());
+    assert!(nozzle::solve_nozzle(&profile(), -0.1, K).is_err());
+}
@@ -0,0 +1,80 @@
+use gas_dynamics::flow_func::{self, K};
+use gas_dynamics::newton::SolverError;
+use gas_dynamics::shock;
+
+// Tabulated values are given with 4-5 significant digits
+const TOLERANCE: f64 = 1e-4;
+
+fn assert_close(name: &str, value: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (value - expected).abs() < tolerance,
+        "{name} = {value}, expected {expected}"
+    );
+}
+
+fn lambda_of_mach(mach: f64) -> f64 {
+    flow_func::lambda_mach(mach, K).unwrap().0.x
+}
+
+#[test]
+fn normal_shock_mach_2() {
+    let s = shock::normal_shock(lambda_of_mach(2.0), K);
+    assert_close("M1", s.mach1, 2.0, 1e-12);
+    assert_close("M2", s.mach2, 0.5774, TOLERANCE);
+    assert_close("p2/p1", s.pressure_ratio, 4.5, 1e-12);
+    assert_close("T2/T1", s.temperature_ratio, 1.6875, 1e-12);
+    assert_close("rho2/rho1", s.density_ratio, 2.6667, TOLERANCE);
+    assert_close("sigma", s.sigma, 0.7209, TOLERANCE);
+}
+
+#[test]
+fn oblique_shock_mach_2() {
+    let theta = 10f64.to_radians();
+    let (weak, strong) = shock::shock_angle(2.0, theta, K).unwrap();
+    let strong = strong.expect("strong solution");
+    assert_close("beta weak", weak.x.to_degrees(), 39.3139, TOLERANCE);
+    assert_close("beta strong", strong.x.to_degrees(), 83.7001, TOLERANCE);
+
+    // Shock angle gives back the deflection angle
+    let s = shock::oblique_shock(2.0, weak.x, K);
+    assert_close("theta", s.theta, theta, 1e-12);
+    assert_close("M2 weak", s.mach2, 1.6405, TOLERANCE);
+    assert_close("p2/p1 weak", s.normal.pressure_ratio, 1.7066, TOLERANCE);
+}
+
+#[test]
+fn oblique_shock_detachment() {
+    // Weak and strong solutions meet at the detachment angle
+    let theta_max = shock::detachment_angle(2.0, K);
+    assert_close("theta max", theta_max.to_degrees(), 22.9735, TOLERANCE);
+
+    let (weak, strong) = shock::shock_angle(2.0, theta_max, K).unwrap();
+    let beta = shock::detachment_beta(2.0, K);
+    assert_close("beta weak", weak.x, beta, 1e-6);
+    assert_close("beta strong", strong.unwrap().x, beta, 1e-6);
+
+    assert!(matches!(
+        shock::shock_angle(2.0, theta_max + 1e-3, K),
+        Err(SolverError::OutOfDomain { .. })
+    ));
+    assert!(shock::shock_angle(0.5, 0.1, K).is_err());
+}
+
+#[test]
+fn prandtl_meyer_inverse() {
+    let nu = shock::prandtl_meyer(2.0, K);
+    assert_close("nu", nu.to_degrees(), 26.3798, TOLERANCE);
+    assert_close(
+        "nu max",
+        shock::prandtl_meyer_max(K).to_degrees(),
+        130.4541,
+        TOLERANCE,
+    );
+
+    for mach in [1.1, 2.0, 3.5, 10.0] {
+        let (m, _) = shock::mach_prandtl_meyer(shock::prandtl_meyer(mach, K), K).unwrap();
+        assert_close("M", m.x, mach, 1e-6 * mach);
+    }
+
+    assert!(shock::mach_prandtl_meyer(-0.1, K).is_err());
+}
@@ -2,117 +2,15 @@
 
 use fltk::{draw, enums};
 
-use crate::draw_primitives::{draw_axis, draw_rest, draw_spring, draw_weight};
-use crate::param_list::{ParamList, Parametrized};
-use crate::pendulum_model::{ParametrizedModel, PendulumModel};
-
-// Model of an elastic pendulum
-const THETA_0: f64 = 45.0;
-const X_0: f64 = 0.0;
-const LENGTH: f64 = 1.0;
-const MASS: f64 = 1.0;
-const K: f64 = 30.0;
-const G: f64 = 9.81;
-const DT: f64 = 0.05;
-
-pub struct ElasticPendulumModel {
-    params: ParamList,
-    time: f64,
-    dtime: f64,
-    length: f64,
-    mass: f64,
-    k: f64,
-    theta: f64,
-    theta_v: f64,
-    theta_a: f64,
-    x: f64,
-    x_v: f64,
-    x_a: f64,
-    g: f64,
-}
-
-impl ElasticPendulumModel {
-    pub fn new() -> Self {
-        let params = ParamList::from([
-            ("theta0", "θ(0)", THETA_0, "Initial pendulum angle"),
-            ("L", "L", LENGTH, "Spring rest length"),
-            ("x0", "x(0)", X_0, "Initial spring stretch"),
-            ("g", "g", G, "Gravitational constant"),
-            ("dtime", "ΔT", DT, "Time step delta"),
-        ]);
-
-        Self {
-            params,
-            time: 0.0,
-            dtime: DT,
-            length: LENGTH,
-            mass: MASS,
-            k: K,
-            theta: 0.0,
-            theta_v: 0.0,
-            theta_a: 0.0,
-            x: 0.0,
-            x_v: 0.0,
-            x_a: 0.0,
-            g: G,
-        }
-    }
-}
-
-impl ParametrizedModel for ElasticPendulumModel {}
-
-impl Parametrized for ElasticPendulumModel {
-    fn copy_params_from(&mut self, other: &ParamList) {
-        self.params.copy_from(other);
-    }
-    fn get_params(&self) -> ParamList {
-        self.params.clone()
-    }
-}
-
-impl PendulumModel for ElasticPendulumModel {
-    fn label(&self) -> &'static str {
-        "Elastic Pendulum"
-    }
-
-    fn time(&self) -> f64 {
-        self.time
-    }
-
-    fn restart(&mut self) {
-        self.time = 0.0;
-        self.dtime = self.params.get_by_key("dtime");
-
-        self.length = self.params.get_by_key("L");
-
-        self.theta = self.params.get_by_key("theta0").to_radians();
-        self.theta_v = 0.0;
-        self.theta_a = 0.0;
-
-        self.x = self.params.get_by_key("x0");
-        self.x_v = 0.0;
-        self.x_a = 0.0;
-
-        self.g = self.params.get_by_key("g");
-    }
+use pendulum_models::elastic_pendulum::ElasticPendulumModel;
+use pendulum_models::pendulum_model::PendulumModel;
 
-    fn step(&mut self) {
-        self.time += self.dtime;
-
-        let l = self.length + self.x;
-
-        self.x_a = l * (self.theta_v * self.theta_v) - self.k * self.x / self.mass
-            + self.g * self.theta.cos();
-
-        self.theta_a = -(self.g * self.theta.sin() + 2.0 * self.x_v * self.theta_v) / l;
-
-        self.x_v += self.x_a * self.dtime;
-        self.x += self.x_v * self.dtime;
+use crate::draw_primitives::{draw_axis, draw_rest, draw_spring, draw_weight};
+use crate::pendulum_view::{PendulumModelView, PendulumView};
 
-        self.theta_v += self.theta_a * self.dtime;
-        self.theta += self.theta_v * self.dtime;
-    }
+impl PendulumModelView for ElasticPendulumModel {}
 
+impl PendulumView for ElasticPendulumModel {
     fn draw(&self, w: i32, h: i32, offs: &draw::Offscreen) {
         // Geometry sizes
         const MARGIN: i32 = 20;
@@ -141,7 +39,7 @@ impl PendulumModel for ElasticPendulumModel {
 
         draw::draw_text2(self.label(), w / 2, MARGIN, 0, 0, enums::Align::Center);
 
-        let theta_str = format!("θ = {:.2}°", self.theta.to_degrees());
+        let theta_str = format!("θ = {:.2}°", self.theta().to_degrees());
         draw::draw_text2(
             &theta_str,
             w / 2,
@@ -157,11 +55,11 @@ impl PendulumModel for ElasticPendulumModel {
         // Coordinates of the pivotal point
         let x0: i32 = w / 2;
         let y0: i32 = h / 4;
-        let l0: f64 = self.length * (h / 3) as f64;
-        let l: f64 = l0 * (1.0 + self.x);
+        let l0: f64 = self.length() * (h / 3) as f64;
+        let l: f64 = l0 * (1.0 + self.stretch());
 
         // Coordinates of the weight
-        let angle: f64 = 90_f64.to_radians() - self.theta;
+        let angle: f64 = 90_f64.to_radians() - self.theta();
         let x1: i32 = (x0 as f64 + l * (angle).cos()) as i32;
         let y1: i32 = (y0 as f64 + l * (angle).sin()) as i32;
 
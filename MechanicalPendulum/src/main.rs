@@ -1,30 +1,32 @@
 #![allow(clippy::too_many_lines)]
 #![allow(clippy::cast_possible_wrap)]
 #![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::collapsible_if)]
 
 mod coupled_pendulums;
 mod double_pendulum;
 mod draw_primitives;
 mod elastic_pendulum;
-mod frame_saver;
-mod param_list;
 mod param_table_widget;
-mod pendulum_model;
+mod pendulum_view;
 mod res;
 mod simple_pendulum;
 
-use coupled_pendulums::CoupledPendulumsModel;
-use double_pendulum::DoublePendulumModel;
-use elastic_pendulum::ElasticPendulumModel;
-use frame_saver::FrameSaver;
-use param_list::{ParamList, Parametrized};
 use param_table_widget::ParamTableWidget;
-use pendulum_model::{ParametrizedModel, PendulumModel};
+use pendulum_view::{PendulumModelView, PendulumView};
 use res::IconsAssets;
-use simple_pendulum::SimplePendulumModel;
+
+use pendulum_models::coupled_pendulums::CoupledPendulumsModel;
+use pendulum_models::double_pendulum::DoublePendulumModel;
+use pendulum_models::elastic_pendulum::ElasticPendulumModel;
+use pendulum_models::param_list::{ParamList, Parametrized};
+use pendulum_models::pendulum_model::PendulumModel;
+use pendulum_models::simple_pendulum::SimplePendulumModel;
 
 use fltk::{app, button, draw, enums, frame, group, prelude::*, window};
 
+use mechanics_common::frame_saver::FrameSaver;
+
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::{thread, time::Duration};
@@ -51,7 +53,7 @@ pub enum Message {
 
 struct ModelList {
     current_model: usize,
-    models: Vec<Box<dyn ParametrizedModel>>,
+    models: Vec<Box<dyn PendulumModelView>>,
 }
 
 impl ModelList {
@@ -76,6 +78,9 @@ impl PendulumModel for ModelList {
     fn step(&mut self) {
         self.models[self.current_model].step();
     }
+}
+
+impl PendulumView for ModelList {
     fn draw(&self, w: i32, h: i32, offs: &draw::Offscreen) {
         self.models[self.current_model].draw(w, h, offs);
     }
@@ -90,24 +95,6 @@ impl Parametrized for ModelList {
     }
 }
 
-trait OffscreenSaver {
-    fn save_offscreen(&mut self, offs: &mut draw::Offscreen, w: i32, h: i32);
-}
-
-impl OffscreenSaver for FrameSaver {
-    fn save_offscreen(&mut self, offs: &mut draw::Offscreen, w: i32, h: i32) {
-        match draw::capture_offscreen(offs, w, h) {
-            Ok(img) => {
-                let data = img.to_rgb_data();
-                self.save_frame(&data, img.width(), img.height());
-            }
-            Err(error) => {
-                eprintln!("Cannot capture frame to image. Error: {error}");
-            }
-        }
-    }
-}
-
 fn main() {
     const MODEL_SIZE: i32 = HEIGHT - MARGIN * 2;
 
@@ -2,7 +2,7 @@
 
 use fltk::{app, draw, enums, group, input, prelude::*, table};
 
-use crate::param_list::{ParamList, Parametrized};
+use pendulum_models::param_list::{ParamList, Parametrized};
 
 use std::cell::RefCell;
 use std::rc::Rc;
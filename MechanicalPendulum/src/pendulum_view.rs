@@ -0,0 +1,10 @@
+use fltk::draw;
+
+use pendulum_models::pendulum_model::ParametrizedModel;
+
+// Drawing of the pendulum model into an offscreen buffer
+pub trait PendulumView {
+    fn draw(&self, w: i32, h: i32, offs: &draw::Offscreen);
+}
+
+pub trait PendulumModelView: ParametrizedModel + PendulumView {}
@@ -3,86 +3,15 @@
 
 use fltk::{draw, enums};
 
-use crate::draw_primitives::{draw_axis, draw_cord, draw_rest, draw_weight};
-use crate::param_list::{ParamList, Parametrized};
-use crate::pendulum_model::{ParametrizedModel, PendulumModel};
-
-// Model of a simple pendulum
-const THETA_0: f64 = 45.0;
-const LENGTH: f64 = 1.0;
-const G: f64 = 9.81;
-const DT: f64 = 0.05;
-
-pub struct SimplePendulumModel {
-    pub params: ParamList,
-    time: f64,
-    dtime: f64,
-    theta: f64,
-    theta_v: f64,
-    theta_a: f64,
-    length: f64,
-    g: f64,
-}
-
-impl SimplePendulumModel {
-    pub fn new() -> Self {
-        let params = ParamList::from([
-            ("theta0", "θ(0)", THETA_0, "Initial pendulum angle"),
-            ("L", "L", LENGTH, "Pendulum length"),
-            ("g", "g", G, "Gravitational constant"),
-            ("dtime", "ΔT", DT, "Time step delta"),
-        ]);
-
-        Self {
-            params,
-            time: 0.0,
-            dtime: DT,
-            theta: 0.0,
-            theta_v: 0.0,
-            theta_a: 0.0,
-            length: LENGTH,
-            g: G,
-        }
-    }
-}
+use pendulum_models::pendulum_model::PendulumModel;
+use pendulum_models::simple_pendulum::SimplePendulumModel;
 
-impl ParametrizedModel for SimplePendulumModel {}
-
-impl Parametrized for SimplePendulumModel {
-    fn copy_params_from(&mut self, other: &ParamList) {
-        self.params.copy_from(other);
-    }
-    fn get_params(&self) -> ParamList {
-        self.params.clone()
-    }
-}
-
-impl PendulumModel for SimplePendulumModel {
-    fn label(&self) -> &'static str {
-        "Simple Pendulum"
-    }
-
-    fn time(&self) -> f64 {
-        self.time
-    }
-
-    fn restart(&mut self) {
-        self.time = 0.0;
-        self.dtime = self.params.get_by_key("dtime");
-        self.theta = self.params.get_by_key("theta0").to_radians();
-        self.theta_v = 0.0;
-        self.theta_a = 0.0;
-        self.length = self.params.get_by_key("L");
-        self.g = self.params.get_by_key("g");
-    }
+use crate::draw_primitives::{draw_axis, draw_cord, draw_rest, draw_weight};
+use crate::pendulum_view::{PendulumModelView, PendulumView};
 
-    fn step(&mut self) {
-        self.time += self.dtime;
-        self.theta_a = -self.g * self.theta.sin() / self.length;
-        self.theta_v += self.theta_a * self.dtime;
-        self.theta += self.theta_v * self.dtime;
-    }
+impl PendulumModelView for SimplePendulumModel {}
 
+impl PendulumView for SimplePendulumModel {
     fn draw(&self, w: i32, h: i32, offs: &draw::Offscreen) {
         // Geometry sizes
         const MARGIN: i32 = 20;
@@ -110,7 +39,7 @@ impl PendulumModel for SimplePendulumModel {
 
         draw::draw_text2(self.label(), w / 2, MARGIN, 0, 0, enums::Align::Center);
 
-        let theta_str = format!("θ = {:.2}°", self.theta.to_degrees());
+        let theta_str = format!("θ = {:.2}°", self.theta().to_degrees());
         draw::draw_text2(
             &theta_str,
             w / 2,
@@ -126,10 +55,10 @@ impl PendulumModel for SimplePendulumModel {
         // Coordinates of the pivotal point
         let x0: i32 = w / 2;
         let y0: i32 = h / 4;
-        let l: f64 = self.length * ((h / 2) as f64);
+        let l: f64 = self.length() * ((h / 2) as f64);
 
         // Coordinates of the weight
-        let angle: f64 = 90_f64.to_radians() - self.theta;
+        let angle: f64 = 90_f64.to_radians() - self.theta();
         let x1: i32 = (x0 as f64 + l * (angle).cos()) as i32;
         let y1: i32 = (y0 as f64 + l * (angle).sin()) as i32;
 
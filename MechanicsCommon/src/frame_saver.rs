@@ -1,5 +1,6 @@
 #![allow(clippy::cast_sign_loss)]
 
+use fltk::{draw, prelude::ImageExt};
 use image::RgbImage;
 
 pub struct FrameSaver {
@@ -36,4 +37,23 @@ impl FrameSaver {
             }
         }
     }
+
+    pub fn save_offscreen(&mut self, offs: &mut draw::Offscreen, w: i32, h: i32) -> bool {
+        match draw::capture_offscreen(offs, w, h) {
+            Ok(img) => {
+                let data = img.to_rgb_data();
+                self.save_frame(&data, img.width(), img.height())
+            }
+            Err(error) => {
+                eprintln!("Cannot capture frame to image. Error: {error}");
+                false
+            }
+        }
+    }
+}
+
+impl Default for FrameSaver {
+    fn default() -> Self {
+        Self::new()
+    }
 }
@@ -0,0 +1,7 @@
+//! Common GUI code shared by the mechanics programs: plot widgets and axes
+//! and recording of simulation frames.
+
+pub mod frame_saver;
+pub mod plot_area;
+pub mod plot_axes;
+pub mod plot_widget;
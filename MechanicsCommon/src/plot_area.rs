@@ -0,0 +1,331 @@
+#![allow(clippy::cast_lossless)]
+#![allow(clippy::cast_possible_truncation)]
+
+use fltk::draw;
+
+#[derive(Copy, Clone)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+pub type Line = (Point, Point);
+
+pub type PlotLines = Vec<Line>;
+
+/// Range of plot coordinates visible in a plot
+#[derive(Copy, Clone)]
+pub struct Area {
+    pub xmin: f64,
+    pub ymin: f64,
+    pub xmax: f64,
+    pub ymax: f64,
+}
+
+impl Area {
+    pub fn is_inside(&self, p: &Point) -> bool {
+        p.x > self.xmin && p.x < self.xmax && p.y > self.ymin && p.y < self.ymax
+    }
+
+    // Same as is_inside, but including the bounds
+    pub fn contains(&self, p: &Point) -> bool {
+        p.x >= self.xmin && p.x <= self.xmax && p.y >= self.ymin && p.y <= self.ymax
+    }
+
+    pub fn width(&self) -> f64 {
+        self.xmax - self.xmin
+    }
+
+    pub fn height(&self) -> f64 {
+        self.ymax - self.ymin
+    }
+
+    /// Part of the line inside of the area (Liang-Barsky algorithm) or None when
+    /// the line is outside or has infinite points
+    pub fn clip(&self, line: &Line) -> Option<Line> {
+        let (a, b) = line;
+        if ![a.x, a.y, b.x, b.y].iter().all(|v| v.is_finite()) {
+            return None;
+        }
+
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        let (mut t0, mut t1): (f64, f64) = (0.0, 1.0);
+        for (p, q) in [
+            (-dx, a.x - self.xmin),
+            (dx, self.xmax - a.x),
+            (-dy, a.y - self.ymin),
+            (dy, self.ymax - a.y),
+        ] {
+            if p == 0.0 {
+                // Line is parallel to the bound and outside of it
+                if q < 0.0 {
+                    return None;
+                }
+            } else if p < 0.0 {
+                t0 = t0.max(q / p);
+            } else {
+                t1 = t1.min(q / p);
+            }
+        }
+
+        if t0 > t1 {
+            return None;
+        }
+
+        Some((
+            Point {
+                x: a.x + t0 * dx,
+                y: a.y + t0 * dy,
+            },
+            Point {
+                x: a.x + t1 * dx,
+                y: a.y + t1 * dy,
+            },
+        ))
+    }
+
+    /// Area with the corners at the points in any order
+    pub fn from_corners(a: &Point, b: &Point) -> Self {
+        Self {
+            xmin: a.x.min(b.x),
+            ymin: a.y.min(b.y),
+            xmax: a.x.max(b.x),
+            ymax: a.y.max(b.y),
+        }
+    }
+
+    /// Area moved by `(dx, dy)`
+    pub fn translate(&self, dx: f64, dy: f64) -> Self {
+        Self {
+            xmin: self.xmin + dx,
+            ymin: self.ymin + dy,
+            xmax: self.xmax + dx,
+            ymax: self.ymax + dy,
+        }
+    }
+
+    /// Area with the bounds mapped to the positions along the axes of the scales
+    pub fn to_scaled(&self, scales: (Scale, Scale)) -> Self {
+        let (sx, sy) = scales;
+        Self {
+            xmin: sx.apply(self.xmin),
+            ymin: sy.apply(self.ymin),
+            xmax: sx.apply(self.xmax),
+            ymax: sy.apply(self.ymax),
+        }
+    }
+
+    /// Inverse of `to_scaled`
+    pub fn from_scaled(&self, scales: (Scale, Scale)) -> Self {
+        let (sx, sy) = scales;
+        Self {
+            xmin: sx.invert(self.xmin),
+            ymin: sy.invert(self.ymin),
+            xmax: sx.invert(self.xmax),
+            ymax: sy.invert(self.ymax),
+        }
+    }
+
+    /// Area scaled by `factor` with the `center` kept in place
+    pub fn zoom(&self, center: &Point, factor: f64) -> Self {
+        Self {
+            xmin: center.x + (self.xmin - center.x) * factor,
+            ymin: center.y + (self.ymin - center.y) * factor,
+            xmax: center.x + (self.xmax - center.x) * factor,
+            ymax: center.y + (self.ymax - center.y) * factor,
+        }
+    }
+}
+
+/// Scale of an axis of the plot
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Scale {
+    #[default]
+    Linear,
+    /// Logarithmic scale is defined only for positive values
+    Log,
+}
+
+impl Scale {
+    /// Position of the value along the axis
+    pub fn apply(self, v: f64) -> f64 {
+        match self {
+            Scale::Linear => v,
+            Scale::Log => v.log10(),
+        }
+    }
+
+    /// Value at the position along the axis
+    pub fn invert(self, v: f64) -> f64 {
+        match self {
+            Scale::Linear => v,
+            Scale::Log => 10f64.powf(v),
+        }
+    }
+}
+
+/// Mapping of the plot area onto a rectangle of pixels
+#[derive(Copy, Clone)]
+pub struct PlotTransform {
+    area: Area,
+    scales: (Scale, Scale),
+    left: f64,
+    top: f64,
+    pixel_x: f64,
+    pixel_y: f64,
+}
+
+impl PlotTransform {
+    /// Map `area` onto the rectangle with top left corner `(x, y)` and size `(w, h)`
+    pub fn new(area: Area, x: i32, y: i32, w: i32, h: i32) -> Self {
+        Self::with_scales(area, (Scale::Linear, Scale::Linear), x, y, w, h)
+    }
+
+    /// Same as `new` with the scales of the x and y axes
+    pub fn with_scales(area: Area, scales: (Scale, Scale), x: i32, y: i32, w: i32, h: i32) -> Self {
+        let (sx, sy) = scales;
+        Self {
+            area,
+            scales,
+            left: x as f64,
+            top: y as f64,
+            pixel_x: (sx.apply(area.xmax) - sx.apply(area.xmin)) / (w as f64),
+            pixel_y: (sy.apply(area.ymax) - sy.apply(area.ymin)) / (h as f64),
+        }
+    }
+
+    pub fn area(&self) -> Area {
+        self.area
+    }
+
+    pub fn scales(&self) -> (Scale, Scale) {
+        self.scales
+    }
+
+    // Size of a single pixel in plot coordinates (in decades on the logarithmic axis)
+    pub fn pixel_x(&self) -> f64 {
+        self.pixel_x
+    }
+
+    pub fn pixel_y(&self) -> f64 {
+        self.pixel_y
+    }
+
+    // Values outside of the domain of the logarithmic scale are mapped to NaN or infinity
+    pub fn get_x(&self, x: f64) -> f64 {
+        let sx = self.scales.0;
+        (sx.apply(x) - sx.apply(self.area.xmin)) / self.pixel_x + self.left
+    }
+
+    pub fn get_y(&self, y: f64) -> f64 {
+        let sy = self.scales.1;
+        (sy.apply(self.area.ymax) - sy.apply(y)) / self.pixel_y + self.top
+    }
+
+    // Inverse mapping of pixel coordinates into plot coordinates
+    pub fn get_plot_point(&self, x: i32, y: i32) -> Point {
+        let (sx, sy) = self.scales;
+        Point {
+            x: sx.invert((x as f64 - self.left) * self.pixel_x + sx.apply(self.area.xmin)),
+            y: sy.invert(sy.apply(self.area.ymax) - (y as f64 - self.top) * self.pixel_y),
+        }
+    }
+
+    pub fn get_coord(&self, x: f64, y: f64) -> draw::Coord<i32> {
+        draw::Coord::<i32>(self.get_x(x) as i32, self.get_y(y) as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: Area = Area {
+        xmin: 0.0,
+        ymin: 0.0,
+        xmax: 10.0,
+        ymax: 5.0,
+    };
+
+    fn point(x: f64, y: f64) -> Point {
+        Point { x, y }
+    }
+
+    fn assert_point(p: &Point, x: f64, y: f64) {
+        assert!(
+            (p.x - x).abs() < 1e-12 && (p.y - y).abs() < 1e-12,
+            "({}, {}), expected ({x}, {y})",
+            p.x,
+            p.y
+        );
+    }
+
+    #[test]
+    fn clip() {
+        // Line inside is kept
+        let (a, b) = AREA.clip(&(point(1.0, 1.0), point(2.0, 3.0))).unwrap();
+        assert_point(&a, 1.0, 1.0);
+        assert_point(&b, 2.0, 3.0);
+
+        // Line across the area is cut at the bounds in its direction
+        let (a, b) = AREA.clip(&(point(-5.0, 2.5), point(15.0, 2.5))).unwrap();
+        assert_point(&a, 0.0, 2.5);
+        assert_point(&b, 10.0, 2.5);
+        let (a, b) = AREA.clip(&(point(12.0, 7.0), point(2.0, -3.0))).unwrap();
+        assert_point(&a, 10.0, 5.0);
+        assert_point(&b, 5.0, 0.0);
+
+        // Lines outside, parallel to the bound outside and with infinite points
+        assert!(AREA.clip(&(point(-2.0, 1.0), point(1.0, 9.0))).is_none());
+        assert!(AREA.clip(&(point(1.0, 6.0), point(9.0, 6.0))).is_none());
+        assert!(
+            AREA.clip(&(point(1.0, 1.0), point(f64::INFINITY, 1.0)))
+                .is_none()
+        );
+        assert!(
+            AREA.clip(&(point(f64::NAN, 1.0), point(2.0, 1.0)))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn zoom_and_translate() {
+        let center = point(2.0, 1.0);
+        let area = AREA.zoom(&center, 0.5);
+        assert_point(&point(area.xmin, area.ymin), 1.0, 0.5);
+        assert_point(&point(area.xmax, area.ymax), 6.0, 3.0);
+        assert!((area.width() - AREA.width() / 2.0).abs() < 1e-12);
+
+        // Zooming back restores the area
+        let area = area.zoom(&center, 2.0);
+        assert_point(&point(area.xmin, area.ymin), AREA.xmin, AREA.ymin);
+        assert_point(&point(area.xmax, area.ymax), AREA.xmax, AREA.ymax);
+
+        let area = AREA.translate(-1.0, 2.0);
+        assert_point(&point(area.xmin, area.ymin), -1.0, 2.0);
+        assert_point(&point(area.xmax, area.ymax), 9.0, 7.0);
+
+        let area = Area::from_corners(&point(3.0, -1.0), &point(-2.0, 4.0));
+        assert_point(&point(area.xmin, area.ymin), -2.0, -1.0);
+        assert_point(&point(area.xmax, area.ymax), 3.0, 4.0);
+    }
+
+    #[test]
+    fn scaled_area() {
+        let area = Area {
+            xmin: 0.0,
+            ymin: 1e-3,
+            xmax: 10.0,
+            ymax: 100.0,
+        };
+        let scales = (Scale::Linear, Scale::Log);
+        let scaled = area.to_scaled(scales);
+        assert_point(&point(scaled.xmin, scaled.ymin), 0.0, -3.0);
+        assert_point(&point(scaled.xmax, scaled.ymax), 10.0, 2.0);
+
+        // Zoom on the logarithmic axis is relative to the decades
+        let zoomed = scaled.zoom(&point(5.0, 0.0), 0.5).from_scaled(scales);
+        assert!((zoomed.ymin - 10f64.powf(-1.5)).abs() < 1e-12);
+        assert!((zoomed.ymax - 10.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,365 @@
+#![allow(clippy::cast_lossless)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+
+use fltk::{draw, enums};
+
+use crate::plot_area::{PlotTransform, Scale};
+
+const AXIS_X: f64 = 0.0;
+const AXIS_Y: f64 = 0.0;
+
+const BOUNDS_COLOR: enums::Color = enums::Color::Black;
+const TICKS_COLOR: enums::Color = enums::Color::Black;
+const AXES_COLOR: enums::Color = enums::Color::Black;
+const TEXT_COLOR: enums::Color = enums::Color::Black;
+const GRID_COLOR: enums::Color = enums::Color::Light2;
+
+const LABEL_FONT_SIZE: i32 = 12;
+
+// Values outside of this range of magnitudes are labeled in exponential notation
+const MIN_FIXED: f64 = 1e-3;
+const MAX_FIXED: f64 = 1e5;
+
+/// Length of the ticks in pixels and the largest number of intervals between
+/// the labeled ticks along each axis
+#[derive(Copy, Clone)]
+pub struct Ticks {
+    pub size: i32,
+    pub count_x: i32,
+    pub count_y: i32,
+}
+
+pub fn draw_bounding_box(t: &PlotTransform) {
+    let area = t.area();
+
+    draw::set_line_style(draw::LineStyle::Solid, 1);
+    draw::set_draw_color(BOUNDS_COLOR);
+    draw::draw_loop3(
+        t.get_coord(area.xmin, area.ymin),
+        t.get_coord(area.xmax, area.ymin),
+        t.get_coord(area.xmax, area.ymax),
+        t.get_coord(area.xmin, area.ymax),
+    );
+}
+
+/// Step of the ticks from 1, 2 or 5 times a power of ten such that there are
+/// at most `max_count` intervals on the range
+pub fn nice_step(min: f64, max: f64, max_count: i32) -> f64 {
+    let raw = (max - min) / f64::from(max_count.max(1));
+    let magnitude = 10f64.powf(raw.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|m| m * magnitude)
+        .find(|&step| step >= raw)
+        .unwrap_or(10.0 * magnitude)
+}
+
+// Multiples of the step on the range with the rounding errors near zero removed
+fn multiples(min: f64, max: f64, step: f64) -> Vec<f64> {
+    const EPS: f64 = 1e-9;
+
+    let first = (min / step - EPS).ceil() as i64;
+    let last = (max / step + EPS).floor() as i64;
+    (first..=last)
+        .map(|i| {
+            let v = i as f64 * step;
+            if v.abs() < step * EPS { 0.0 } else { v }
+        })
+        .collect()
+}
+
+/// Values of the ticks on the linear axis at the multiples of `nice_step`
+pub fn nice_ticks(min: f64, max: f64, max_count: i32) -> Vec<f64> {
+    if !(min.is_finite() && max.is_finite() && max > min) {
+        return vec![];
+    }
+    multiples(min, max, nice_step(min, max, max_count))
+}
+
+/// Values of the ticks on the logarithmic axis at the powers of ten. Every n-th power
+/// is taken when there are more than `max_count` decades on the range
+pub fn log_ticks(min: f64, max: f64, max_count: i32) -> Vec<f64> {
+    if !(min > 0.0 && max.is_finite() && max > min) {
+        return vec![];
+    }
+    let (first, last) = (min.log10() - 1e-9, max.log10() + 1e-9);
+    let every = ((last - first) / f64::from(max_count.max(1)))
+        .ceil()
+        .max(1.0) as i32;
+    let (first, last) = (first.ceil() as i32, last.floor() as i32);
+    (first..=last)
+        .filter(|k| k.rem_euclid(every) == 0)
+        .map(|k| 10f64.powi(k))
+        .collect()
+}
+
+/// Label of the tick value with the precision of the step between ticks
+pub fn format_tick(v: f64, step: f64) -> String {
+    if v == 0.0 {
+        return "0".to_string();
+    }
+    let digits = |a: f64| a.abs().log10().floor() as i32;
+    if v.abs() < MIN_FIXED || v.abs() >= MAX_FIXED {
+        let precision = (digits(v) - digits(step)).clamp(0, 6) as usize;
+        format!("{v:.precision$e}")
+    } else {
+        let precision = (-digits(step)).max(0) as usize;
+        format!("{v:.precision$}")
+    }
+}
+
+// Tick at the plot coordinate with the label of the major tick
+struct Tick {
+    position: f64,
+    label: Option<String>,
+}
+
+// Major ticks at nice values of the range and minor ticks between them: halves of the step
+// on the linear axis and 2..9 times the powers of ten on the logarithmic axis. Values of
+// the range are mapped onto the bounds of the area along the axis
+fn axis_ticks(range: (f64, f64), bounds: (f64, f64), scale: Scale, max_count: i32) -> Vec<Tick> {
+    let (r0, r1) = (scale.apply(range.0), scale.apply(range.1));
+    let (b0, b1) = (scale.apply(bounds.0), scale.apply(bounds.1));
+    if !(r0.is_finite() && r1.is_finite() && r1 > r0) {
+        return vec![];
+    }
+    let tick = |v: f64, label: Option<String>| Tick {
+        position: scale.invert(b0 + (scale.apply(v) - r0) / (r1 - r0) * (b1 - b0)),
+        label,
+    };
+
+    let powers = log_ticks(range.0, range.1, max_count);
+    if scale == Scale::Log && powers.len() > 1 {
+        let mut ticks: Vec<Tick> = powers
+            .iter()
+            .map(|&v| tick(v, Some(format_tick(v, v))))
+            .collect();
+
+        // Minor ticks are omitted when the powers are skipped
+        if powers[1] / powers[0] < 11.0 {
+            for k in (r0.floor() as i32)..=(r1.ceil() as i32) {
+                ticks.extend(
+                    (2..=9)
+                        .map(|m| f64::from(m) * 10f64.powi(k))
+                        .filter(|v| *v > range.0 && *v < range.1)
+                        .map(|v| tick(v, None)),
+                );
+            }
+        }
+        return ticks;
+    }
+
+    // Linear ticks are used on the logarithmic axis shorter than a decade
+    let step = nice_step(range.0, range.1, max_count);
+    multiples(range.0, range.1, step / 2.0)
+        .iter()
+        .map(|&v| {
+            let major = (v / step - (v / step).round()).abs() < 1e-6;
+            tick(v, major.then(|| format_tick(v, step)))
+        })
+        .collect()
+}
+
+/// Ticks at nice values with the numeric labels at the major ticks and optional grid lines
+/// through them. Ranges may differ from the plot area when the plot is drawn in scaled
+/// coordinates, the values of the ranges are mapped onto the bounds of the area
+pub fn draw_axes(
+    t: &PlotTransform,
+    ticks: &Ticks,
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+    grid: bool,
+) {
+    let area = t.area();
+    let (sx, sy) = t.scales();
+    let mut x_ticks = axis_ticks(x_range, (area.xmin, area.xmax), sx, ticks.count_x);
+    let mut y_ticks = axis_ticks(y_range, (area.ymin, area.ymax), sy, ticks.count_y);
+    x_ticks.retain(|k| t.get_x(k.position).is_finite());
+    y_ticks.retain(|k| t.get_y(k.position).is_finite());
+
+    let (x0, x1) = (t.get_x(area.xmin) as i32, t.get_x(area.xmax) as i32);
+    let (y0, y1) = (t.get_y(area.ymin) as i32, t.get_y(area.ymax) as i32);
+
+    if grid {
+        draw::set_line_style(draw::LineStyle::Dot, 1);
+        draw::set_draw_color(GRID_COLOR);
+        for x in x_ticks.iter().filter(|k| k.label.is_some()) {
+            draw::draw_yxline(t.get_x(x.position) as i32, y0, y1);
+        }
+        for y in y_ticks.iter().filter(|k| k.label.is_some()) {
+            draw::draw_xyline(x0, t.get_y(y.position) as i32, x1);
+        }
+    }
+
+    // Minor ticks are drawn with half of the length
+    draw::set_line_style(draw::LineStyle::Solid, 1);
+    draw::set_draw_color(TICKS_COLOR);
+    let length = |k: &Tick| ticks.size / if k.label.is_some() { 1 } else { 2 };
+    for x in &x_ticks {
+        draw::draw_yxline(t.get_x(x.position) as i32, y0, y0 + length(x));
+    }
+    for y in &y_ticks {
+        draw::draw_xyline(x0, t.get_y(y.position) as i32, x0 - length(y));
+    }
+
+    draw::set_draw_color(TEXT_COLOR);
+    draw::set_font(enums::Font::Helvetica, LABEL_FONT_SIZE);
+    for x in &x_ticks {
+        if let Some(label) = &x.label {
+            let x = t.get_x(x.position) as i32;
+            draw::draw_text2(label, x, y0 + ticks.size + 2, 0, 0, enums::Align::Top);
+        }
+    }
+    for y in &y_ticks {
+        if let Some(label) = &y.label {
+            let y = t.get_y(y.position) as i32;
+            draw::draw_text2(label, x0 - ticks.size - 2, y, 0, 0, enums::Align::Right);
+        }
+    }
+}
+
+// Title of the x axis is placed below the middle of the tick labels,
+// title of the y axis is placed above the top of the axis
+pub fn draw_axis_labels(t: &PlotTransform, ticks: &Ticks, x_label: &str, y_label: &str) {
+    let area = t.area();
+
+    draw::set_draw_color(TEXT_COLOR);
+    draw::set_font(enums::Font::HelveticaBold, 14);
+
+    let x0 = t.get_x(area.xmin) as i32;
+    let x1 = t.get_x(area.xmax) as i32;
+    draw::draw_text2(
+        x_label,
+        (x0 + x1) / 2,
+        t.get_y(area.ymin) as i32 + ticks.size + LABEL_FONT_SIZE + 6,
+        0,
+        0,
+        enums::Align::Center | enums::Align::Top,
+    );
+
+    draw::draw_text2(
+        y_label,
+        x0,
+        t.get_y(area.ymax) as i32 - 2,
+        0,
+        0,
+        enums::Align::BottomLeft,
+    );
+}
+
+// Origin axes are drawn only if they are inside the plot area
+pub fn draw_origin_axes(t: &PlotTransform) {
+    let area = t.area();
+
+    draw::set_draw_color(AXES_COLOR);
+    draw::set_line_style(draw::LineStyle::DashDotDot, 1);
+
+    if area.xmin < AXIS_X && AXIS_X < area.xmax {
+        draw::draw_line(
+            t.get_x(AXIS_X) as i32,
+            t.get_y(area.ymin) as i32,
+            t.get_x(AXIS_X) as i32,
+            t.get_y(area.ymax) as i32,
+        );
+    }
+
+    if area.ymin < AXIS_Y && AXIS_Y < area.ymax {
+        draw::draw_line(
+            t.get_x(area.xmin) as i32,
+            t.get_y(AXIS_Y) as i32,
+            t.get_x(area.xmax) as i32,
+            t.get_y(AXIS_Y) as i32,
+        );
+    }
+
+    draw::set_line_style(draw::LineStyle::Solid, 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_values(values: &[f64], expected: &[f64]) {
+        assert_eq!(
+            values.len(),
+            expected.len(),
+            "{values:?}, expected {expected:?}"
+        );
+        for (v, e) in values.iter().zip(expected) {
+            assert!(
+                (v - e).abs() <= 1e-12 * e.abs().max(1.0),
+                "{values:?}, expected {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn steps() {
+        assert_values(&[nice_step(0.0, 10.0, 10)], &[1.0]);
+        assert_values(&[nice_step(0.0, 10.0, 4)], &[5.0]);
+        assert_values(&[nice_step(-3.0, 7.0, 5)], &[2.0]);
+        assert_values(&[nice_step(0.0, 0.07, 10)], &[0.01]);
+        assert_values(&[nice_step(0.0, 300.0, 2)], &[200.0]);
+    }
+
+    #[test]
+    fn linear_ticks() {
+        // Span across zero contains zero exactly
+        let ticks = nice_ticks(-0.3, 0.3, 6);
+        assert_values(&ticks, &[-0.3, -0.2, -0.1, 0.0, 0.1, 0.2, 0.3]);
+        assert!(ticks.contains(&0.0));
+
+        assert_values(&nice_ticks(-3.0, 7.0, 5), &[-2.0, 0.0, 2.0, 4.0, 6.0]);
+        // Bounds at the multiples of the step are kept despite the rounding errors
+        assert_values(
+            &nice_ticks(0.1, 0.7, 6),
+            &[0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7],
+        );
+
+        assert!(nice_ticks(1.0, 1.0, 10).is_empty());
+        assert!(nice_ticks(0.0, f64::INFINITY, 10).is_empty());
+        assert!(nice_ticks(f64::NAN, 1.0, 10).is_empty());
+    }
+
+    #[test]
+    fn logarithmic_ticks() {
+        assert_values(
+            &log_ticks(1e-3, 1e3, 10),
+            &[1e-3, 1e-2, 0.1, 1.0, 10.0, 100.0, 1e3],
+        );
+        assert_values(&log_ticks(0.5, 200.0, 10), &[1.0, 10.0, 100.0]);
+
+        // Every second power is taken on 16 decades
+        assert_values(
+            &log_ticks(1e-16, 1.0, 10),
+            &[1e-16, 1e-14, 1e-12, 1e-10, 1e-8, 1e-6, 1e-4, 1e-2, 1.0],
+        );
+        // Skipped powers are counted from 10^0 on negative and positive exponents
+        assert_values(&log_ticks(1e-5, 1e5, 4), &[1e-3, 1.0, 1e3]);
+
+        // Range under a decade has at most one power
+        assert!(log_ticks(2.0, 8.0, 10).is_empty());
+        assert_values(&log_ticks(5.0, 50.0, 10), &[10.0]);
+
+        assert!(log_ticks(0.0, 1.0, 10).is_empty());
+        assert!(log_ticks(-1.0, 1.0, 10).is_empty());
+    }
+
+    #[test]
+    fn tick_labels() {
+        assert_eq!(format_tick(0.0, 1e-20), "0");
+        assert_eq!(format_tick(2.5, 0.5), "2.5");
+        assert_eq!(format_tick(-20.0, 10.0), "-20");
+        assert_eq!(format_tick(0.25, 0.05), "0.25");
+
+        // Bounds of the fixed notation
+        assert_eq!(format_tick(1e-3, 1e-3), "0.001");
+        assert_eq!(format_tick(9e-4, 1e-4), "9e-4");
+        assert_eq!(format_tick(-2e-4, 1e-4), "-2e-4");
+        assert_eq!(format_tick(99_999.0, 1.0), "99999");
+        assert_eq!(format_tick(1e5, 1e5), "1e5");
+        assert_eq!(format_tick(1.5e5, 5e4), "1.5e5");
+        assert_eq!(format_tick(1e-16, 1e-16), "1e-16");
+    }
+}
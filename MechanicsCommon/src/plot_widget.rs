@@ -0,0 +1,748 @@
+#![allow(clippy::cast_possible_wrap)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_lossless)]
+#![allow(clippy::cast_sign_loss)]
+
+use fltk::{
+    app, draw, enums,
+    prelude::{WidgetBase, WidgetExt},
+    widget, widget_extends,
+};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::plot_area::{Area, Line, PlotLines, PlotTransform, Point, Scale};
+use crate::plot_axes::{self, Ticks};
+
+/// Plotted function, shared with the threads calculating the points
+pub type PlotFunction = Arc<dyn Fn(f64) -> f64 + Send + Sync>;
+
+// Segments of the plots are split while the function at the middle deviates from
+// the segment by more than the tolerance in pixels
+const SAMPLING_TOLERANCE: f64 = 0.5;
+const MAX_SUBDIVISION: u32 = 10;
+
+const MARGIN: i32 = 20;
+const TICKS: Ticks = Ticks {
+    size: 10,
+    count_x: 10,
+    count_y: 10,
+};
+
+// Scale of the area for a single step of the mouse wheel
+const ZOOM_STEP: f64 = 1.2;
+
+// Selection smaller than this fraction of the area is ignored
+const MIN_SELECTION: f64 = 0.01;
+
+const SELECTION_COLOR: enums::Color = enums::Color::Dark3;
+
+// Legend and readout are drawn in the corners of the plot area with rows of text
+const LEGEND_FONT_SIZE: i32 = 12;
+const LEGEND_ROW: i32 = 16;
+const LEGEND_SAMPLE: i32 = 20;
+const LEGEND_PADDING: i32 = 5;
+const LEGEND_BG_COLOR: enums::Color = enums::Color::White;
+const LEGEND_BOUNDS_COLOR: enums::Color = enums::Color::Dark3;
+const LEGEND_TEXT_COLOR: enums::Color = enums::Color::Black;
+const HIDDEN_COLOR: enums::Color = enums::Color::Light1;
+const CROSSHAIR_COLOR: enums::Color = enums::Color::Dark2;
+
+type AreaCallback = Box<dyn FnMut(Area)>;
+
+// Mouse drag in progress: moving of the area with the cursor position and
+// the area at the start or selection of the area to zoom into
+#[derive(Copy, Clone)]
+enum Drag {
+    Pan { start: Point, area: Area },
+    Select { start: Point },
+}
+
+#[derive(Clone)]
+pub struct PlotFunctionInfo {
+    pub f: PlotFunction,
+    pub color: enums::Color,
+    pub name: String,
+}
+
+impl PlotFunctionInfo {
+    /// Segments of the plot on `line_count` intervals of the area of the transform (uniform
+    /// in pixels) that are subdivided where the curve is not straight up to the tolerance
+    pub fn calc_points(&self, line_count: i32, t: &PlotTransform) -> PlotLines {
+        self.calc_points_with_progress(line_count, t, |_| true)
+            .unwrap_or_default()
+    }
+
+    /// Same as `calc_points` with the fraction of the calculated intervals passed
+    /// to `progress`. Calculation is cancelled when `progress` returns false
+    pub fn calc_points_with_progress<F>(
+        &self,
+        line_count: i32,
+        t: &PlotTransform,
+        mut progress: F,
+    ) -> Option<PlotLines>
+    where
+        F: FnMut(f64) -> bool,
+    {
+        let area = t.area();
+        let sx = t.scales().0;
+        let x0 = sx.apply(area.xmin);
+        let px: f64 = (sx.apply(area.xmax) - x0) / (line_count as f64);
+        let point = |x: f64| Point { x, y: (self.f)(x) };
+
+        let mut lines = PlotLines::new();
+        let mut a = point(area.xmin);
+        for i in 1..=line_count {
+            let b = point(sx.invert(x0 + px * (i as f64)));
+            self.subdivide(a, b, t, MAX_SUBDIVISION, &mut lines);
+            a = b;
+
+            if !progress(f64::from(i) / f64::from(line_count)) {
+                return None;
+            }
+        }
+        Some(lines)
+    }
+
+    fn subdivide(&self, a: Point, b: Point, t: &PlotTransform, depth: u32, lines: &mut PlotLines) {
+        let area = t.area();
+        let (sx, sy) = t.scales();
+        // Values out of the domain of the logarithmic scale are not drawn
+        let visible = |p: &Point| sy.apply(p.y).is_finite();
+        let finite = visible(&a) && visible(&b);
+
+        // Segments outside of the function domain or on the same side
+        // of the area are not refined
+        let undefined = !visible(&a) && !visible(&b);
+        let outside = (a.y > area.ymax && b.y > area.ymax) || (a.y < area.ymin && b.y < area.ymin);
+        if undefined || outside {
+            if finite {
+                lines.push((a, b));
+            }
+            return;
+        }
+
+        if depth == 0 {
+            // Jump over the whole area at the smallest segment is a discontinuity
+            let height = t.get_y(area.ymin) - t.get_y(area.ymax);
+            if finite && (t.get_y(b.y) - t.get_y(a.y)).abs() < height {
+                lines.push((a, b));
+            }
+            return;
+        }
+
+        let x = sx.invert((sx.apply(a.x) + sx.apply(b.x)) / 2.0);
+        let m = Point { x, y: (self.f)(x) };
+        let deviation = (t.get_y(m.y) - (t.get_y(a.y) + t.get_y(b.y)) / 2.0).abs();
+        if finite && visible(&m) && deviation <= SAMPLING_TOLERANCE {
+            lines.push((a, b));
+            return;
+        }
+
+        self.subdivide(a, m, t, depth - 1, lines);
+        self.subdivide(m, b, t, depth - 1, lines);
+    }
+}
+
+/// Tabulated data drawn as a polyline
+#[derive(Clone)]
+pub struct PlotData {
+    pub points: Vec<Point>,
+    pub color: enums::Color,
+    pub name: String,
+}
+
+/// Auxiliary line drawn over the plots (e.g. a solution of an equation)
+#[derive(Copy, Clone)]
+pub struct Guide {
+    pub line: Line,
+    pub color: enums::Color,
+}
+
+/// Point marked over the plots with a text label (e.g. a readout under the cursor)
+#[derive(Clone)]
+pub struct Marker {
+    pub point: Point,
+    pub color: enums::Color,
+    pub label: String,
+}
+
+#[derive(Default)]
+struct AxisLabels {
+    x: String,
+    y: String,
+}
+
+// Plot area is drawn inside of the widget with margins for ticks, labels and titles
+fn plot_transform(area: Area, scales: (Scale, Scale), w: &widget::Widget) -> PlotTransform {
+    PlotTransform::with_scales(
+        area,
+        scales,
+        w.x() + MARGIN * 2 + TICKS.size,
+        w.y() + MARGIN,
+        w.w() - MARGIN * 3 - TICKS.size,
+        w.h() - MARGIN * 3 - TICKS.size,
+    )
+}
+
+type Rect = (i32, i32, i32, i32);
+
+fn scaled_point(p: &Point, scales: (Scale, Scale)) -> Point {
+    Point {
+        x: scales.0.apply(p.x),
+        y: scales.1.apply(p.y),
+    }
+}
+
+// Rows of the box of text lines in the top right or the top left corner of the plot area
+fn text_box_rows(t: &PlotTransform, lines: &[&str], right: bool) -> Vec<Rect> {
+    draw::set_font(enums::Font::Helvetica, LEGEND_FONT_SIZE);
+    let width = lines
+        .iter()
+        .map(|l| draw::width(l) as i32)
+        .max()
+        .unwrap_or(0)
+        + LEGEND_SAMPLE
+        + LEGEND_PADDING * 3;
+
+    let area = t.area();
+    let x = if right {
+        t.get_x(area.xmax) as i32 - LEGEND_PADDING - width
+    } else {
+        t.get_x(area.xmin) as i32 + LEGEND_PADDING
+    };
+    let y = t.get_y(area.ymax) as i32 + LEGEND_PADDING;
+
+    (0..lines.len())
+        .map(|i| (x, y + LEGEND_ROW * i as i32, width, LEGEND_ROW))
+        .collect()
+}
+
+// Box of rows with the samples of the lines and text
+fn draw_text_box(rows: &[Rect], entries: &[(&str, enums::Color, enums::Color)]) {
+    let (Some(first), Some(last)) = (rows.first(), rows.last()) else {
+        return;
+    };
+    let height = last.1 + last.3 - first.1;
+    draw::draw_rect_fill(first.0, first.1, first.2, height, LEGEND_BG_COLOR);
+    draw::set_draw_color(LEGEND_BOUNDS_COLOR);
+    draw::draw_rect(first.0, first.1, first.2, height);
+
+    draw::set_font(enums::Font::Helvetica, LEGEND_FONT_SIZE);
+    for (&(x, y, w, h), &(text, sample, text_color)) in rows.iter().zip(entries) {
+        let sample_x = x + LEGEND_PADDING;
+        draw::set_line_style(draw::LineStyle::Solid, 2);
+        draw::set_draw_color(sample);
+        draw::draw_line(sample_x, y + h / 2, sample_x + LEGEND_SAMPLE, y + h / 2);
+
+        draw::set_draw_color(text_color);
+        let text_x = sample_x + LEGEND_SAMPLE + LEGEND_PADDING;
+        draw::draw_text2(text, text_x, y, w - (text_x - x), h, enums::Align::Left);
+    }
+    draw::set_line_style(draw::LineStyle::Solid, 1);
+}
+
+fn legend_rows(t: &PlotTransform, plots: &[(PlotFunctionInfo, PlotLines)]) -> Vec<Rect> {
+    let names: Vec<&str> = plots.iter().map(|p| p.0.name.as_str()).collect();
+    text_box_rows(t, &names, true)
+}
+
+// Legend with the hidden plots greyed out
+fn draw_legend(t: &PlotTransform, plots: &[(PlotFunctionInfo, PlotLines)], hidden: &[String]) {
+    let entries: Vec<(&str, enums::Color, enums::Color)> = plots
+        .iter()
+        .map(|p| {
+            if hidden.contains(&p.0.name) {
+                (p.0.name.as_str(), HIDDEN_COLOR, HIDDEN_COLOR)
+            } else {
+                (p.0.name.as_str(), p.0.color, LEGEND_TEXT_COLOR)
+            }
+        })
+        .collect();
+    draw_text_box(&legend_rows(t, plots), &entries);
+}
+
+// Lines through the cursor and the values of the visible plots at the cursor
+fn draw_crosshair(
+    t: &PlotTransform,
+    p: &Point,
+    plots: &[(PlotFunctionInfo, PlotLines)],
+    hidden: &[String],
+) {
+    let area = t.area();
+    let (x, y) = (t.get_x(p.x) as i32, t.get_y(p.y) as i32);
+    draw::set_draw_color(CROSSHAIR_COLOR);
+    draw::set_line_style(draw::LineStyle::Dot, 1);
+    draw::draw_line(x, t.get_y(area.ymin) as i32, x, t.get_y(area.ymax) as i32);
+    draw::draw_line(t.get_x(area.xmin) as i32, y, t.get_x(area.xmax) as i32, y);
+
+    let mut lines = vec![(format!("x = {:.5}", p.x), LEGEND_BG_COLOR)];
+    lines.extend(
+        plots
+            .iter()
+            .filter(|p| !hidden.contains(&p.0.name))
+            .map(|pl| (format!("{} = {:.5}", pl.0.name, (pl.0.f)(p.x)), pl.0.color)),
+    );
+
+    let texts: Vec<&str> = lines.iter().map(|l| l.0.as_str()).collect();
+    let entries: Vec<(&str, enums::Color, enums::Color)> = lines
+        .iter()
+        .map(|l| (l.0.as_str(), l.1, LEGEND_TEXT_COLOR))
+        .collect();
+    draw_text_box(&text_box_rows(t, &texts, false), &entries);
+}
+
+#[derive(Clone)]
+pub struct PlotWidget {
+    inner: widget::Widget,
+    area: Rc<RefCell<Area>>,
+    scales: Rc<RefCell<(Scale, Scale)>>,
+    plots: Rc<RefCell<Vec<(PlotFunctionInfo, PlotLines)>>>,
+    data: Rc<RefCell<Vec<PlotData>>>,
+    guides: Rc<RefCell<Vec<Guide>>>,
+    markers: Rc<RefCell<Vec<Marker>>>,
+    labels: Rc<RefCell<AxisLabels>>,
+    selection: Rc<RefCell<Option<Line>>>,
+    home: Rc<RefCell<Area>>,
+    on_area: Rc<RefCell<Option<AreaCallback>>>,
+    hidden: Rc<RefCell<Vec<String>>>,
+    crosshair: Rc<RefCell<Option<Point>>>,
+    crosshair_enabled: Rc<RefCell<bool>>,
+}
+
+impl PlotWidget {
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        const BOUNDS_COLOR: enums::Color = enums::Color::Dark3;
+        const BG_COLOR: enums::Color = enums::Color::White;
+
+        let mut inner = widget::Widget::default()
+            .with_pos(x, y)
+            .with_size(width, height);
+
+        let area = Area {
+            xmin: -1.0,
+            xmax: 1.0,
+            ymin: -1.0,
+            ymax: 1.0,
+        };
+        let home = Rc::from(RefCell::from(area));
+        let area = Rc::from(RefCell::from(area));
+        let scales = Rc::from(RefCell::from((Scale::Linear, Scale::Linear)));
+
+        let plots: Vec<(PlotFunctionInfo, PlotLines)> = Vec::new();
+        let plots = Rc::from(RefCell::from(plots));
+
+        let data: Vec<PlotData> = Vec::new();
+        let data = Rc::from(RefCell::from(data));
+
+        let guides: Vec<Guide> = Vec::new();
+        let guides = Rc::from(RefCell::from(guides));
+
+        let markers: Vec<Marker> = Vec::new();
+        let markers = Rc::from(RefCell::from(markers));
+
+        let labels = Rc::from(RefCell::from(AxisLabels::default()));
+
+        let selection: Rc<RefCell<Option<Line>>> = Rc::from(RefCell::from(None));
+        let on_area: Rc<RefCell<Option<AreaCallback>>> = Rc::from(RefCell::from(None));
+
+        let hidden: Rc<RefCell<Vec<String>>> = Rc::from(RefCell::from(Vec::new()));
+        let crosshair: Rc<RefCell<Option<Point>>> = Rc::from(RefCell::from(None));
+        let crosshair_enabled = Rc::from(RefCell::from(false));
+
+        inner.draw({
+            let area = area.clone();
+            let scales = scales.clone();
+            let plots = plots.clone();
+            let data = data.clone();
+            let guides = guides.clone();
+            let markers = markers.clone();
+            let labels = labels.clone();
+            let selection = selection.clone();
+            let hidden = hidden.clone();
+            let crosshair = crosshair.clone();
+            move |i| {
+                let area = area.borrow();
+                let hidden = hidden.borrow();
+                let plots = plots.borrow();
+                let data = data.borrow();
+                let guides = guides.borrow();
+                let markers = markers.borrow();
+                let labels = labels.borrow();
+
+                let t = plot_transform(*area, *scales.borrow(), i);
+
+                // Set default style for ranges and ticks
+                draw::set_line_style(draw::LineStyle::Solid, 1);
+
+                // Clean draw area with backgorund color
+                draw::draw_rect_fill(i.x(), i.y(), i.w(), i.h(), BG_COLOR);
+
+                // Widget bounds
+                draw::set_draw_color(BOUNDS_COLOR);
+                draw::draw_rect(i.x(), i.y(), i.w(), i.h());
+
+                plot_axes::draw_bounding_box(&t);
+                plot_axes::draw_axes(
+                    &t,
+                    &TICKS,
+                    (area.xmin, area.xmax),
+                    (area.ymin, area.ymax),
+                    true,
+                );
+                plot_axes::draw_origin_axes(&t);
+                plot_axes::draw_axis_labels(&t, &TICKS, &labels.x, &labels.y);
+
+                // Lines out of the domain of the logarithmic scale are skipped
+                let draw_line = |l: &Line| {
+                    let (x1, y1) = (t.get_x(l.0.x), t.get_y(l.0.y));
+                    let (x2, y2) = (t.get_x(l.1.x), t.get_y(l.1.y));
+                    if [x1, y1, x2, y2].iter().all(|v| v.is_finite()) {
+                        draw::draw_line(x1 as i32, y1 as i32, x2 as i32, y2 as i32);
+                    }
+                };
+
+                // Draw plots
+                if !plots.is_empty() {
+                    let mut width: i32 = (plots.len() * 3 - 1) as i32; // Width of the lower-most plot
+                    for p in plots.iter() {
+                        if hidden.contains(&p.0.name) {
+                            width -= 3;
+                            continue;
+                        }
+
+                        draw::set_draw_color(p.0.color);
+                        draw::set_line_style(
+                            draw::LineStyle::Solid | draw::LineStyle::CapRound,
+                            width,
+                        );
+
+                        for l in p.1.iter().filter_map(|l| area.clip(l)) {
+                            draw_line(&l);
+                        }
+
+                        width -= 3; // Decrease width of plots lines as it goes to the top
+                    }
+                }
+
+                // Draw data
+                draw::set_line_style(draw::LineStyle::Solid | draw::LineStyle::CapRound, 2);
+                for d in data.iter() {
+                    draw::set_draw_color(d.color);
+                    for l in d.points.windows(2).filter_map(|l| area.clip(&(l[0], l[1]))) {
+                        draw_line(&l);
+                    }
+                }
+
+                // Draw guides over the plots
+                draw::set_line_style(draw::LineStyle::Solid, 1);
+                for g in guides.iter() {
+                    draw::set_draw_color(g.color);
+                    draw_line(&g.line);
+                }
+
+                // Draw markers with labels on top
+                draw::set_font(enums::Font::Helvetica, 12);
+                for m in markers.iter().filter(|m| area.contains(&m.point)) {
+                    const RADIUS: i32 = 3;
+                    let (x, y) = (t.get_x(m.point.x) as i32, t.get_y(m.point.y) as i32);
+                    draw::draw_circle_fill(x - RADIUS, y - RADIUS, RADIUS * 2, m.color);
+
+                    // Label is kept inside of the plot area
+                    let align = if m.point.x < area.xmin + area.width() * 0.5 {
+                        enums::Align::BottomLeft
+                    } else {
+                        enums::Align::BottomRight
+                    };
+                    draw::set_draw_color(m.color);
+                    draw::draw_text2(&m.label, x, y - RADIUS, 0, 0, align);
+                }
+
+                // Rectangle of the area selected with the mouse
+                if let Some((a, b)) = *selection.borrow() {
+                    let (x1, y1) = (t.get_x(a.x) as i32, t.get_y(a.y) as i32);
+                    let (x2, y2) = (t.get_x(b.x) as i32, t.get_y(b.y) as i32);
+                    draw::set_draw_color(SELECTION_COLOR);
+                    draw::set_line_style(draw::LineStyle::Dash, 1);
+                    draw::draw_rect(x1.min(x2), y1.min(y2), (x2 - x1).abs(), (y2 - y1).abs());
+                }
+
+                if let Some(p) = *crosshair.borrow() {
+                    draw_crosshair(&t, &p, &plots, &hidden);
+                }
+
+                if !plots.is_empty() {
+                    draw_legend(&t, &plots, &hidden);
+                }
+
+                draw::set_line_style(draw::LineStyle::Solid, 0);
+            }
+        });
+
+        inner.handle(move |_i, _ev| false);
+
+        Self {
+            inner,
+            area,
+            scales,
+            plots,
+            data,
+            guides,
+            markers,
+            labels,
+            selection,
+            home,
+            on_area,
+            hidden,
+            crosshair,
+            crosshair_enabled,
+        }
+    }
+
+    /// Handle mouse events with the cursor position in plot coordinates.
+    /// The handler returns true when the event is consumed. Clicks on the legend
+    /// and the crosshair are handled before the handler
+    pub fn handle_mouse<F>(&mut self, mut handler: F)
+    where
+        F: FnMut(enums::Event, Point) -> bool + 'static,
+    {
+        let area = self.area.clone();
+        let scales = self.scales.clone();
+        let mut plot = self.clone();
+        self.inner.handle(move |i, ev| match ev {
+            // Accept the cursor to receive move events
+            enums::Event::Enter => true,
+            enums::Event::Push
+            | enums::Event::Drag
+            | enums::Event::Released
+            | enums::Event::Move
+            | enums::Event::Leave
+            | enums::Event::MouseWheel => {
+                let t = plot_transform(*area.borrow(), *scales.borrow(), i);
+                let (x, y) = app::event_coords();
+                plot.handle_overlay(ev, &t, x, y) || handler(ev, t.get_plot_point(x, y))
+            }
+            _ => false,
+        });
+    }
+
+    // Toggle of the plots by the click on the legend and moving of the crosshair.
+    // Returns true when the event is consumed
+    fn handle_overlay(&mut self, ev: enums::Event, t: &PlotTransform, x: i32, y: i32) -> bool {
+        match ev {
+            enums::Event::Push => {
+                let name = {
+                    let plots = self.plots.borrow();
+                    legend_rows(t, &plots)
+                        .iter()
+                        .position(|r| x >= r.0 && x < r.0 + r.2 && y >= r.1 && y < r.1 + r.3)
+                        .map(|i| plots[i].0.name.clone())
+                };
+                let Some(name) = name else {
+                    return false;
+                };
+
+                let mut hidden = self.hidden.borrow_mut();
+                if let Some(i) = hidden.iter().position(|h| *h == name) {
+                    hidden.remove(i);
+                } else {
+                    hidden.push(name);
+                }
+                drop(hidden);
+                self.redraw();
+                true
+            }
+            enums::Event::Move | enums::Event::Drag | enums::Event::Leave => {
+                if *self.crosshair_enabled.borrow() {
+                    let p = t.get_plot_point(x, y);
+                    *self.crosshair.borrow_mut() =
+                        (ev == enums::Event::Move && t.area().contains(&p)).then_some(p);
+                    self.redraw();
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Show lines through the cursor with the values of the plots
+    pub fn set_crosshair(&mut self, enabled: bool) {
+        *self.crosshair_enabled.borrow_mut() = enabled;
+        if !enabled {
+            *self.crosshair.borrow_mut() = None;
+        }
+    }
+
+    /// Navigate the plot with the mouse: the wheel zooms around the cursor, drag with
+    /// the left button moves the area, drag with the right button (or the left button
+    /// with Shift) selects the area to zoom into and double click resets the view.
+    /// New area is passed to the callback when the action is finished. Logarithmic axes
+    /// are moved and zoomed by the same number of decades across the area
+    pub fn enable_navigation<F: FnMut(Area) + 'static>(&mut self, on_area: F) {
+        *self.on_area.borrow_mut() = Some(Box::new(on_area));
+
+        let mut plot = self.clone();
+        let mut drag: Option<Drag> = None;
+        self.handle_mouse(move |ev, p| match ev {
+            enums::Event::MouseWheel => {
+                let scales = plot.get_scales();
+                let factor = ZOOM_STEP.powi(app::event_dy_value());
+                let area = plot.get_area().to_scaled(scales);
+                let center = scaled_point(&p, scales);
+                plot.change_area(area.zoom(&center, factor).from_scaled(scales));
+                true
+            }
+            enums::Event::Push => {
+                if app::event_clicks() {
+                    drag = None;
+                    plot.reset_view();
+                } else if app::event_mouse_button() == app::MouseButton::Right
+                    || app::event_state().contains(enums::Shortcut::Shift)
+                {
+                    drag = Some(Drag::Select { start: p });
+                } else {
+                    drag = Some(Drag::Pan {
+                        start: p,
+                        area: plot.get_area(),
+                    });
+                }
+                true
+            }
+            enums::Event::Drag => match drag {
+                Some(Drag::Pan { start, area }) => {
+                    // Cursor is converted to the coordinates of the area at the start
+                    let scales = plot.get_scales();
+                    let (area, start) = (area.to_scaled(scales), scaled_point(&start, scales));
+                    let current = plot.get_area().to_scaled(scales);
+                    let p = scaled_point(&p, scales);
+                    let x = area.xmin + (p.x - current.xmin) / current.width() * area.width();
+                    let y = area.ymin + (p.y - current.ymin) / current.height() * area.height();
+                    let area = area.translate(start.x - x, start.y - y);
+                    plot.set_area(area.from_scaled(scales));
+                    plot.redraw();
+                    true
+                }
+                Some(Drag::Select { start }) => {
+                    *plot.selection.borrow_mut() = Some((start, p));
+                    plot.redraw();
+                    true
+                }
+                None => false,
+            },
+            enums::Event::Released => match drag.take() {
+                Some(Drag::Pan { .. }) => {
+                    plot.change_area(plot.get_area());
+                    true
+                }
+                Some(Drag::Select { start }) => {
+                    plot.selection.borrow_mut().take();
+
+                    let scales = plot.get_scales();
+                    let area = plot.get_area().to_scaled(scales);
+                    let selected = Area::from_corners(&start, &p);
+                    let scaled = selected.to_scaled(scales);
+                    if scaled.width() > area.width() * MIN_SELECTION
+                        && scaled.height() > area.height() * MIN_SELECTION
+                    {
+                        plot.change_area(selected);
+                    } else {
+                        plot.redraw();
+                    }
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        });
+    }
+
+    // Area changed by the navigation
+    fn change_area(&mut self, area: Area) {
+        self.set_area(area);
+        self.redraw();
+        if let Some(f) = self.on_area.borrow_mut().as_mut() {
+            f(area);
+        }
+    }
+
+    /// Area that is shown when the view is reset
+    pub fn set_home_area(&mut self, area: Area) {
+        *self.home.borrow_mut() = area;
+    }
+
+    pub fn reset_view(&mut self) {
+        let home = *self.home.borrow();
+        self.change_area(home);
+    }
+
+    pub fn set_area(&mut self, new_area: Area) {
+        *self.area.borrow_mut() = new_area;
+    }
+
+    pub fn get_area(&self) -> Area {
+        *self.area.borrow()
+    }
+
+    /// Scales of the x and y axes. Bounds of the area on the logarithmic axis
+    /// should be positive
+    pub fn set_scales(&mut self, x: Scale, y: Scale) {
+        *self.scales.borrow_mut() = (x, y);
+    }
+
+    pub fn get_scales(&self) -> (Scale, Scale) {
+        *self.scales.borrow()
+    }
+
+    /// Mapping of the current area onto the pixels of the widget
+    pub fn transform(&self) -> PlotTransform {
+        plot_transform(*self.area.borrow(), *self.scales.borrow(), &self.inner)
+    }
+
+    pub fn set_axis_labels(&mut self, x_label: &str, y_label: &str) {
+        *self.labels.borrow_mut() = AxisLabels {
+            x: x_label.to_string(),
+            y: y_label.to_string(),
+        };
+    }
+
+    /// Plots that are not hidden with the legend
+    pub fn visible_plots(&self) -> Vec<(PlotFunctionInfo, PlotLines)> {
+        let hidden = self.hidden.borrow();
+        self.plots
+            .borrow()
+            .iter()
+            .filter(|p| !hidden.contains(&p.0.name))
+            .cloned()
+            .collect()
+    }
+
+    pub fn clear_plots(&mut self) {
+        self.plots.borrow_mut().clear();
+    }
+
+    pub fn add_plot(&mut self, new_plot: (PlotFunctionInfo, PlotLines)) {
+        self.plots
+            .borrow_mut()
+            .push((new_plot.0.clone(), new_plot.1));
+    }
+
+    pub fn set_data(&mut self, new_data: Vec<PlotData>) {
+        *self.data.borrow_mut() = new_data;
+    }
+
+    pub fn set_guides(&mut self, new_guides: Vec<Guide>) {
+        *self.guides.borrow_mut() = new_guides;
+    }
+
+    pub fn set_markers(&mut self, new_markers: Vec<Marker>) {
+        *self.markers.borrow_mut() = new_markers;
+    }
+
+    pub fn redraw(&mut self) {
+        self.inner.redraw();
+    }
+}
+
+widget_extends!(PlotWidget, widget::Widget, inner);
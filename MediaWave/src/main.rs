@@ -1,5 +1,3 @@
-mod frame_saver;
-
 use fltk::{app, prelude::*};
 
 use std::cell::RefCell;
@@ -7,12 +5,11 @@ use std::rc::Rc;
 use std::{thread, time::Duration};
 
 mod main_window;
-mod pipe_model;
 mod plot_widget;
 mod res;
 
 use crate::main_window::MainWindow;
-use crate::pipe_model::PipeModel;
+use pipe_flow::pipe_model::PipeModel;
 
 const WIDTH: i32 = 700;
 const HEIGHT: i32 = 500;
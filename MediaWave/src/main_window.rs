@@ -1,14 +1,13 @@
 #![allow(clippy::similar_names)]
 #![allow(clippy::too_many_lines)]
+#![allow(clippy::collapsible_if)]
 
 use fltk::{app, button, draw, enums, group, input, menu, prelude::*, window};
 
-use crate::frame_saver::FrameSaver;
+use crate::plot_widget::PlotWidget;
 use crate::res::IconsAssets;
-use crate::{
-    pipe_model::{BOUNDARY_OPEN, BOUNDARY_SEALED, PipeModel},
-    plot_widget::PlotWidget,
-};
+use mechanics_common::frame_saver::FrameSaver;
+use pipe_flow::pipe_model::{BOUNDARY_OPEN, BOUNDARY_SEALED, PipeModel};
 
 const MARGIN: i32 = 10;
 pub struct MainWindow {
@@ -362,15 +361,6 @@ impl MainWindow {
         );
         self.frame_offs.end();
 
-        match draw::capture_offscreen(&mut self.frame_offs, w, h) {
-            Ok(img) => {
-                let data = img.to_rgb_data();
-                self.frame_saver
-                    .save_frame(&data, img.width(), img.height());
-            }
-            Err(error) => {
-                eprintln!("Cannot capture frame to image. Error: {error}");
-            }
-        }
+        self.frame_saver.save_offscreen(&mut self.frame_offs, w, h);
     }
 }
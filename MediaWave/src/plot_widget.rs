@@ -6,26 +6,22 @@
 
 use fltk::{draw, enums, prelude::*, widget, widget_extends};
 
+use mechanics_common::plot_area::{Area, PlotTransform};
+use mechanics_common::plot_axes::{self, Ticks};
+
 use std::cell::RefCell;
 use std::rc::Rc;
 
-#[derive(Copy, Clone)]
-struct Area {
-    xmin: f64,
-    xmax: f64,
-    ymin: f64,
-    ymax: f64,
-}
-
 const MARGIN: i32 = 25;
-const TICK_SIZE: i32 = 15;
-const TICKS_COUNT: i32 = 20;
+const TICKS: Ticks = Ticks {
+    size: 15,
+    count_x: 10,
+    count_y: 8,
+};
 
 pub struct PlotWidget {
     inner: widget::Widget,
-    area: Area,
-    pixel_x: f64,
-    pixel_y: f64,
+    transform: PlotTransform,
     offs: Rc<RefCell<draw::Offscreen>>,
 }
 
@@ -48,26 +44,32 @@ impl PlotWidget {
             }
         });
 
-        Self {
-            inner,
-            area: Area {
+        let transform = PlotTransform::new(
+            Area {
                 xmin: 0.0,
-                xmax: 0.0,
+                xmax: 1.0,
                 ymin: 0.0,
-                ymax: 0.0,
+                ymax: 1.0,
             },
-            pixel_x: 0.0,
-            pixel_y: 0.0,
+            0,
+            0,
+            width,
+            height,
+        );
+
+        Self {
+            inner,
+            transform,
             offs,
         }
     }
 
     fn get_x(&self, x: f64) -> f64 {
-        (x - self.area.xmin) / self.pixel_x + (MARGIN + TICK_SIZE) as f64
+        self.transform.get_x(x)
     }
 
     fn get_y(&self, y: f64) -> f64 {
-        (self.area.ymax - y) / self.pixel_y + MARGIN as f64
+        self.transform.get_y(y)
     }
 
     pub fn draw_plot(&mut self, x_points: &[f64], y_points: &[f64], len: f64, time: Option<f64>) {
@@ -95,10 +97,13 @@ impl PlotWidget {
             ymin: -2.0,
             ymax: 2.0,
         };
-        self.area = area;
-
-        self.pixel_x = (area.xmax - area.xmin) / (self.w() - (MARGIN * 2 + TICK_SIZE)) as f64;
-        self.pixel_y = (area.ymax - area.ymin) / (self.h() - (MARGIN * 2 + TICK_SIZE)) as f64;
+        self.transform = PlotTransform::new(
+            area,
+            MARGIN + TICKS.size,
+            MARGIN,
+            width - (MARGIN * 2 + TICKS.size),
+            height - (MARGIN * 3 + TICKS.size),
+        );
 
         self.offs.borrow().begin();
 
@@ -115,14 +120,15 @@ impl PlotWidget {
         match time {
             None => {}
             Some(t) => {
+                // Time is shown at the right end of the x axis, below its title
                 let time_str = format!("time: {t:.4}");
                 draw::draw_text2(
                     &time_str,
-                    self.get_x((area.xmax - area.xmin) / 2.0 + area.xmin) as i32,
-                    self.get_y(area.ymin) as i32 + TICK_SIZE,
+                    self.get_x(area.xmax) as i32,
+                    self.get_y(area.ymin) as i32 + TICKS.size + 16,
                     0,
                     0,
-                    enums::Align::Top,
+                    enums::Align::TopRight,
                 );
             }
         }
@@ -145,78 +151,18 @@ impl PlotWidget {
             );
         }
 
-        // Bounding box
-        draw::set_line_style(draw::LineStyle::Solid, 1);
-        draw::set_draw_color(enums::Color::Black);
-        draw::begin_loop();
-        draw::vertex(self.get_x(area.xmin), self.get_y(area.ymin));
-        draw::vertex(self.get_x(area.xmin), self.get_y(area.ymax));
-        draw::vertex(self.get_x(area.xmax), self.get_y(area.ymax));
-        draw::vertex(self.get_x(area.xmax), self.get_y(area.ymin));
-        draw::end_loop();
-
-        // Ticks
-        let dx = (area.xmax - area.xmin) / TICKS_COUNT as f64;
-        for i in 0..=TICKS_COUNT {
-            draw::draw_yxline(
-                self.get_x(area.xmin + dx * (i as f64)) as i32,
-                self.get_y(area.ymin) as i32,
-                self.get_y(area.ymin) as i32 + TICK_SIZE / (if i % 2 == 0 { 1 } else { 2 }),
-            );
-        }
-
-        let dy = (area.ymax - area.ymin) / TICKS_COUNT as f64;
-        for i in 0..=TICKS_COUNT {
-            draw::draw_xyline(
-                self.get_x(area.xmin) as i32,
-                self.get_y(area.ymin + dy * (i as f64)) as i32,
-                self.get_x(area.xmin) as i32 - TICK_SIZE / (if i % 2 == 0 { 1 } else { 2 }),
-            );
-        }
-
-        // Plot ranges
-        draw::set_draw_color(enums::Color::Black);
-        draw::set_font(enums::Font::Helvetica, 12);
-
-        let xmin_str = format!("{:.1}", area.xmin);
-        draw::draw_text2(
-            &xmin_str,
-            self.get_x(area.xmin) as i32,
-            self.get_y(area.ymin) as i32 + TICK_SIZE + 2,
-            0,
-            0,
-            enums::Align::TopLeft,
-        );
-
-        let xmax_str = format!("{:.1}", area.xmax);
-        draw::draw_text2(
-            &xmax_str,
-            self.get_x(area.xmax) as i32,
-            self.get_y(area.ymin) as i32 + TICK_SIZE + 2,
-            0,
-            0,
-            enums::Align::TopRight,
-        );
-
-        let ymin_str = format!("{:.1}", area.ymin);
-        draw::draw_text2(
-            &ymin_str,
-            self.get_x(area.xmin) as i32 - TICK_SIZE - 2,
-            self.get_y(area.ymin) as i32,
-            0,
-            0,
-            enums::Align::Right,
-        );
-
-        let ymax_str = format!("{:.1}", area.ymax);
-        draw::draw_text2(
-            &ymax_str,
-            self.get_x(area.xmin) as i32 - TICK_SIZE - 2,
-            self.get_y(area.ymax) as i32,
-            0,
-            0,
-            enums::Align::Right,
+        // Bounding box, ticks with labels and grid
+        let t = &self.transform;
+        plot_axes::draw_bounding_box(t);
+        plot_axes::draw_axes(
+            t,
+            &TICKS,
+            (area.xmin, area.xmax),
+            (area.ymin, area.ymax),
+            true,
         );
+        // Quantity on the y axis is named by the title of the plot
+        plot_axes::draw_axis_labels(t, &TICKS, "x", "");
 
         // Axis
         draw::set_line_style(draw::LineStyle::DashDot, 1);
@@ -228,18 +174,6 @@ impl PlotWidget {
             self.get_y(AXIS_Y) as i32,
         );
 
-        // Axis label
-        draw::set_font(enums::Font::Helvetica, 12);
-        let yaxis_str = format!("{AXIS_Y:.1}");
-        draw::draw_text2(
-            &yaxis_str,
-            self.get_x(area.xmin) as i32 - TICK_SIZE - 2,
-            self.get_y(AXIS_Y) as i32,
-            0,
-            0,
-            enums::Align::Right,
-        );
-
         // Draw plot
         draw::set_line_style(draw::LineStyle::Solid, 1);
         draw::set_draw_color(enums::Color::Red);
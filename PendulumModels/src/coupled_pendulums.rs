@@ -0,0 +1,138 @@
+use crate::param_list::{ParamList, Parametrized};
+use crate::pendulum_model::{ParametrizedModel, PendulumModel};
+
+const THETA1_0: f64 = 45.0;
+const THETA2_0: f64 = 30.0;
+const LENGTH: f64 = 1.0;
+const MASS: f64 = 1.0;
+const K: f64 = 30.0;
+const G: f64 = 9.81;
+const DT: f64 = 0.05;
+
+/// Model of two simple pendulums linked with a spring
+pub struct CoupledPendulumsModel {
+    pub params: ParamList,
+    time: f64,
+    dtime: f64,
+    length: f64,
+    mass: f64,
+    k: f64,
+    theta1: f64,
+    theta2: f64,
+    omega1: f64,
+    omega2: f64,
+    a: f64,
+    b: f64,
+    g: f64,
+}
+
+impl CoupledPendulumsModel {
+    /// Create model with default parameters
+    pub fn new() -> Self {
+        let params = ParamList::from([
+            (
+                "theta1_0",
+                "θ1(0)",
+                THETA1_0,
+                "Initial angle of left pendulum",
+            ),
+            (
+                "theta2_0",
+                "θ2(0)",
+                THETA2_0,
+                "Initial angle of right pendulum",
+            ),
+            ("L", "L", LENGTH, "Pendulum length"),
+            ("mass", "m", MASS, "Mass of each pendulum"),
+            ("k", "k", K, "Spring constant"),
+            ("g", "g", G, "Gravitational constant"),
+            ("dtime", "ΔT", DT, "Time step delta"),
+        ]);
+
+        Self {
+            params,
+            time: 0.0,
+            dtime: DT,
+            length: LENGTH,
+            mass: MASS,
+            k: K,
+            theta1: 0.0,
+            theta2: 0.0,
+            omega1: 0.0,
+            omega2: 0.0,
+            a: 0.0,
+            b: 0.0,
+            g: G,
+        }
+    }
+
+    /// Current angle of the left pendulum in radians
+    pub fn theta1(&self) -> f64 {
+        self.theta1
+    }
+
+    /// Current angle of the right pendulum in radians
+    pub fn theta2(&self) -> f64 {
+        self.theta2
+    }
+
+    /// Length of each pendulum
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+}
+
+impl Default for CoupledPendulumsModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParametrizedModel for CoupledPendulumsModel {}
+
+impl Parametrized for CoupledPendulumsModel {
+    fn copy_params_from(&mut self, other: &ParamList) {
+        self.params.copy_from(other);
+    }
+    fn get_params(&self) -> ParamList {
+        self.params.clone()
+    }
+}
+
+impl PendulumModel for CoupledPendulumsModel {
+    fn label(&self) -> &'static str {
+        "Coupled pendulums"
+    }
+
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    fn restart(&mut self) {
+        self.time = 0.0;
+        self.dtime = self.params.get_by_key("dtime");
+
+        self.theta1 = self.params.get_by_key("theta1_0").to_radians();
+        self.theta2 = self.params.get_by_key("theta2_0").to_radians();
+
+        self.length = self.params.get_by_key("L");
+        self.mass = self.params.get_by_key("mass");
+        self.k = self.params.get_by_key("k");
+        self.g = self.params.get_by_key("g");
+
+        self.omega1 = (self.g / self.length).sqrt();
+        self.omega2 = (self.g / self.length + 2.0 * self.k / self.mass).sqrt();
+
+        self.a = self.theta1 + self.theta2;
+        self.b = self.theta1 - self.theta2;
+    }
+
+    fn step(&mut self) {
+        self.time += self.dtime;
+
+        self.theta1 = self.a * (self.omega1 * self.time).cos() / 2.0
+            + self.b * (self.omega2 * self.time).cos() / 2.0;
+        self.theta2 = self.a * (self.omega1 * self.time).cos() / 2.0
+            - self.b * (self.omega2 * self.time).cos() / 2.0;
+    }
+}
@@ -0,0 +1,133 @@
+use crate::param_list::{ParamList, Parametrized};
+use crate::pendulum_model::{ParametrizedModel, PendulumModel};
+
+// Model of an elastic pendulum
+const THETA_0: f64 = 45.0;
+const X_0: f64 = 0.0;
+const LENGTH: f64 = 1.0;
+const MASS: f64 = 1.0;
+const K: f64 = 30.0;
+const G: f64 = 9.81;
+const DT: f64 = 0.05;
+
+/// Model of a weight on a spring swinging in the gravity field
+pub struct ElasticPendulumModel {
+    params: ParamList,
+    time: f64,
+    dtime: f64,
+    length: f64,
+    mass: f64,
+    k: f64,
+    theta: f64,
+    theta_v: f64,
+    theta_a: f64,
+    x: f64,
+    x_v: f64,
+    x_a: f64,
+    g: f64,
+}
+
+impl ElasticPendulumModel {
+    /// Create model with default parameters
+    pub fn new() -> Self {
+        let params = ParamList::from([
+            ("theta0", "θ(0)", THETA_0, "Initial pendulum angle"),
+            ("L", "L", LENGTH, "Spring rest length"),
+            ("x0", "x(0)", X_0, "Initial spring stretch"),
+            ("g", "g", G, "Gravitational constant"),
+            ("dtime", "ΔT", DT, "Time step delta"),
+        ]);
+
+        Self {
+            params,
+            time: 0.0,
+            dtime: DT,
+            length: LENGTH,
+            mass: MASS,
+            k: K,
+            theta: 0.0,
+            theta_v: 0.0,
+            theta_a: 0.0,
+            x: 0.0,
+            x_v: 0.0,
+            x_a: 0.0,
+            g: G,
+        }
+    }
+
+    /// Current angle of the pendulum in radians
+    pub fn theta(&self) -> f64 {
+        self.theta
+    }
+
+    /// Current stretch of the spring relative to its rest length
+    pub fn stretch(&self) -> f64 {
+        self.x
+    }
+
+    /// Rest length of the spring
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+}
+
+impl Default for ElasticPendulumModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParametrizedModel for ElasticPendulumModel {}
+
+impl Parametrized for ElasticPendulumModel {
+    fn copy_params_from(&mut self, other: &ParamList) {
+        self.params.copy_from(other);
+    }
+    fn get_params(&self) -> ParamList {
+        self.params.clone()
+    }
+}
+
+impl PendulumModel for ElasticPendulumModel {
+    fn label(&self) -> &'static str {
+        "Elastic Pendulum"
+    }
+
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    fn restart(&mut self) {
+        self.time = 0.0;
+        self.dtime = self.params.get_by_key("dtime");
+
+        self.length = self.params.get_by_key("L");
+
+        self.theta = self.params.get_by_key("theta0").to_radians();
+        self.theta_v = 0.0;
+        self.theta_a = 0.0;
+
+        self.x = self.params.get_by_key("x0");
+        self.x_v = 0.0;
+        self.x_a = 0.0;
+
+        self.g = self.params.get_by_key("g");
+    }
+
+    fn step(&mut self) {
+        self.time += self.dtime;
+
+        let l = self.length + self.x;
+
+        self.x_a = l * (self.theta_v * self.theta_v) - self.k * self.x / self.mass
+            + self.g * self.theta.cos();
+
+        self.theta_a = -(self.g * self.theta.sin() + 2.0 * self.x_v * self.theta_v) / l;
+
+        self.x_v += self.x_a * self.dtime;
+        self.x += self.x_v * self.dtime;
+
+        self.theta_v += self.theta_a * self.dtime;
+        self.theta += self.theta_v * self.dtime;
+    }
+}
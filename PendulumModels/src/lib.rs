@@ -0,0 +1,9 @@
+//! Models of mechanical pendulums: simple, elastic, coupled and double
+//! pendulum, with lists of editable parameters.
+
+pub mod coupled_pendulums;
+pub mod double_pendulum;
+pub mod elastic_pendulum;
+pub mod param_list;
+pub mod pendulum_model;
+pub mod simple_pendulum;
@@ -1,6 +1,7 @@
 // Parameter ID, displayed name, value, tooltip
 type Parameter = (String, String, f64, String);
 
+/// List of named model parameters
 pub struct ParamList {
     pub params: Vec<Parameter>,
 }
@@ -12,14 +13,17 @@ impl ParamList {
         }
     }
 
+    /// Value of parameter by index
     pub fn get(&self, id: usize) -> f64 {
         self.params[id].2
     }
 
+    /// Set value of parameter by index
     pub fn set(&mut self, id: usize, val: f64) {
         self.params[id].2 = val;
     }
 
+    /// Value of parameter by ID. Panics if there is no such parameter
     pub fn get_by_key(&self, key: &str) -> f64 {
         match self.params.iter().find(|&x| x.0.eq(key)) {
             Some(x) => x.2,
@@ -34,10 +38,12 @@ impl ParamList {
     //     }
     // }
 
+    /// Displayed name of parameter by index
     pub fn get_title(&self, id: usize) -> String {
         self.params[id].1.clone()
     }
 
+    /// Description of parameter by index
     pub fn get_tooltip(&self, id: usize) -> String {
         self.params[id].3.clone()
     }
@@ -54,11 +60,21 @@ impl ParamList {
         self.params.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
     pub fn copy_from(&mut self, other: &ParamList) {
         self.params.clone_from(&other.params);
     }
 }
 
+impl Default for ParamList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Clone for ParamList {
     fn clone(&self) -> Self {
         Self {
@@ -0,0 +1,16 @@
+use crate::param_list::Parametrized;
+
+/// Base pendulum model trait
+pub trait PendulumModel {
+    /// Displayed name of the model
+    fn label(&self) -> &'static str;
+    /// Time since the start of the simulation
+    fn time(&self) -> f64;
+    /// Restart the simulation with current parameters
+    fn restart(&mut self);
+    /// Perform single step of the simulation
+    fn step(&mut self);
+}
+
+/// Pendulum model with a list of parameters
+pub trait ParametrizedModel: PendulumModel + Parametrized {}
@@ -0,0 +1,97 @@
+use crate::param_list::{ParamList, Parametrized};
+use crate::pendulum_model::{ParametrizedModel, PendulumModel};
+
+// Model of a simple pendulum
+const THETA_0: f64 = 45.0;
+const LENGTH: f64 = 1.0;
+const G: f64 = 9.81;
+const DT: f64 = 0.05;
+
+/// Model of a simple gravity pendulum
+pub struct SimplePendulumModel {
+    pub params: ParamList,
+    time: f64,
+    dtime: f64,
+    theta: f64,
+    theta_v: f64,
+    theta_a: f64,
+    length: f64,
+    g: f64,
+}
+
+impl SimplePendulumModel {
+    /// Create model with default parameters
+    pub fn new() -> Self {
+        let params = ParamList::from([
+            ("theta0", "θ(0)", THETA_0, "Initial pendulum angle"),
+            ("L", "L", LENGTH, "Pendulum length"),
+            ("g", "g", G, "Gravitational constant"),
+            ("dtime", "ΔT", DT, "Time step delta"),
+        ]);
+
+        Self {
+            params,
+            time: 0.0,
+            dtime: DT,
+            theta: 0.0,
+            theta_v: 0.0,
+            theta_a: 0.0,
+            length: LENGTH,
+            g: G,
+        }
+    }
+
+    /// Current angle of the pendulum in radians
+    pub fn theta(&self) -> f64 {
+        self.theta
+    }
+
+    /// Length of the pendulum
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+}
+
+impl Default for SimplePendulumModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParametrizedModel for SimplePendulumModel {}
+
+impl Parametrized for SimplePendulumModel {
+    fn copy_params_from(&mut self, other: &ParamList) {
+        self.params.copy_from(other);
+    }
+    fn get_params(&self) -> ParamList {
+        self.params.clone()
+    }
+}
+
+impl PendulumModel for SimplePendulumModel {
+    fn label(&self) -> &'static str {
+        "Simple Pendulum"
+    }
+
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    fn restart(&mut self) {
+        self.time = 0.0;
+        self.dtime = self.params.get_by_key("dtime");
+        self.theta = self.params.get_by_key("theta0").to_radians();
+        self.theta_v = 0.0;
+        self.theta_a = 0.0;
+        self.length = self.params.get_by_key("L");
+        self.g = self.params.get_by_key("g");
+    }
+
+    fn step(&mut self) {
+        self.time += self.dtime;
+        self.theta_a = -self.g * self.theta.sin() / self.length;
+        self.theta_v += self.theta_a * self.dtime;
+        self.theta += self.theta_v * self.dtime;
+    }
+}
@@ -0,0 +1,4 @@
+//! Model of the acoustic waves in a pipe of limited length with boundary
+//! conditions on the pipe ends.
+
+pub mod pipe_model;
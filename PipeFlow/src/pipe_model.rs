@@ -4,12 +4,15 @@ const DEFAULT_A: f64 = 1.0;
 const DEFAULT_RHO: f64 = 1.0;
 const DEFAULT_SIGMA: f64 = 0.1;
 
+/// Boundary condition b*u + c*p = 0 on the end of the pipe
 pub struct BoundaryCondition {
     b: f64,
     c: f64,
 }
 
+/// Sealed end of the pipe (u = 0)
 pub const BOUNDARY_SEALED: BoundaryCondition = BoundaryCondition { b: 0.0, c: 1.0 };
+/// Open end of the pipe (p = 0)
 pub const BOUNDARY_OPEN: BoundaryCondition = BoundaryCondition { b: 1.0, c: 0.0 };
 
 const DEFAULT_CONDITION_L: BoundaryCondition = BOUNDARY_SEALED;
@@ -92,31 +95,43 @@ fn simq2(a: [[f64; 2]; 2], b: [f64; 2]) -> Option<[f64; 2]> {
     }
 }
 
+/// Model of a fluid in a pipe of limited length
 pub struct PipeModel {
+    /// Current time
     pub time: f64,
+    /// Length of the pipe
     pub len: f64,
+    /// Number of nodes
     pub n: usize,
+    /// Courant number
     pub sigma: f64,
+    /// Speed of sound
     pub a: f64,
+    /// Density of the fluid
     pub rho: f64,
     rho_a: f64,
     h: f64,
     h2: f64,
     tau: f64,
     tau_h: f64,
+    /// Boundary conditions on the left and right ends
     pub bl: BoundaryCondition,
     pub br: BoundaryCondition,
+    /// Coordinates of the nodes
     pub x: Vec<f64>,
     x2: Vec<f64>,
+    /// Velocities and pressures in the cells between nodes
     pub u1: Vec<f64>,
     pub p1: Vec<f64>,
     u: Vec<f64>,
     p: Vec<f64>,
+    /// IDs of initial conditions for velocities and pressures
     pub un_id: String,
     pub pn_id: String,
 }
 
 impl PipeModel {
+    /// Create model of the pipe with length `len` and `n` nodes
     pub fn new(len: f64, n: usize) -> Self {
         Self {
             time: 0.0,
@@ -143,7 +158,7 @@ impl PipeModel {
         }
     }
 
-    // Reset the simulation
+    /// Reset the simulation
     pub fn reset(&mut self) {
         self.time = 0.0;
 
@@ -183,7 +198,7 @@ impl PipeModel {
         self.p = vec![0.0; self.n + 1];
     }
 
-    // Perform single step of the simulation
+    /// Perform single step of the simulation
     pub fn step(&mut self) {
         self.time += self.tau;
 
@@ -221,12 +236,12 @@ impl PipeModel {
         }
     }
 
-    // Set ID for initial conditions for velocities function
+    /// Set ID for initial conditions for velocities function
     pub fn set_initial_u(&mut self, id: &str) {
         self.un_id = id.to_string();
     }
 
-    // Set ID for initial conditions for pressures function
+    /// Set ID for initial conditions for pressures function
     pub fn set_initial_p(&mut self, id: &str) {
         self.pn_id = id.to_string();
     }
@@ -0,0 +1,5 @@
+//! Model of the surface waves of a fluid in a rectangular channel of
+//! infinite length.
+
+pub mod surface_functions;
+pub mod wave_model;
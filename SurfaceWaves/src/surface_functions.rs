@@ -1,5 +1,6 @@
 use std::f64::consts::PI;
 
+/// Shape of the initial surface on the interval [0, 1]
 pub type SurfaceFunc = fn(f64) -> f64;
 
 pub fn linear_surface(x: f64) -> f64 {
@@ -19,10 +19,9 @@ const DEFAULT_XN: usize = 30;
 const DEFAULT_ZN: usize = 15;
 const DEFAULT_MAXN: usize = 50;
 
-// Describes a moveable point
-// x,z: coordinates
-// x0,z0: original coordinates
-
+/// Describes a moveable point
+/// x,z: coordinates
+/// x0,z0: original coordinates
 #[derive(Copy, Clone)]
 pub struct Point {
     pub x: f64,
@@ -41,26 +40,31 @@ struct Particle {
     sigma: f64,
 }
 
-// Model of water cross-section
+/// Model of water cross-section
 pub struct WaveModel {
-    // Gravitational constant
+    /// Gravitational constant
     pub g: f64,
-    // Model parameters
+    /// Width of the channel section
     pub delta: f64,
+    /// Amplitude of the initial surface
     pub epsilon: f64,
+    /// Depth of the channel
     pub h: f64,
-    // Current time, time delta for single step and
-    // number of frame since the start of simulation
+    /// Current time
     pub time: f64,
+    /// Time delta for single step
     pub dtime: f64,
+    // Number of frame since the start of simulation
     frame: i32,
-    // Number of points along each coordinate
+    /// Number of points along x coordinate
     pub xn: usize,
+    /// Number of points along z coordinate
     pub zn: usize,
+    /// Number of terms of Fourier series
     pub maxn: usize,
     // Parameters vectors
     particles: Vec<Particle>,
-    // Points data vector
+    /// Points data vector, `zn` points of each column are stored sequentially
     pub points: Points,
     // Current surface function
     surface_func: surface_functions::SurfaceFunc,
@@ -69,6 +73,7 @@ pub struct WaveModel {
 }
 
 impl WaveModel {
+    /// Create model with default parameters
     pub fn make_model() -> Self {
         Self {
             g: DEFAULT_G,
@@ -105,6 +110,7 @@ impl WaveModel {
         }
     }
 
+    /// Restart the simulation with current parameters
     pub fn reset(&mut self) {
         self.time = 0.0;
         self.frame = 0;
@@ -130,6 +136,7 @@ impl WaveModel {
         }
     }
 
+    /// Perform single step of the simulation
     pub fn step(&mut self) {
         #[cfg(debug_assertions)]
         let t_init = SystemTime::now();
@@ -147,6 +154,7 @@ impl WaveModel {
         self.benchmarks.push(t_init.elapsed().unwrap().as_micros());
     }
 
+    /// Select initial surface: 0 - linear, 1 - sine, 2 - cosine, 3 - halfsine
     pub fn set_surface_func(&mut self, n: i32) {
         self.surface_func = match n {
             0 => surface_functions::linear_surface,
@@ -238,6 +246,7 @@ impl WaveModel {
                 .sum::<f64>()
     }
 
+    /// Average time of a single step in microseconds
     #[cfg(debug_assertions)]
     pub fn benchmark(&self) -> u128 {
         (self.benchmarks.iter().sum::<u128>() as f64 / self.benchmarks.len() as f64) as u128
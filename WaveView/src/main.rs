@@ -1,5 +1,3 @@
-mod frame_saver;
-
 use fltk::{app, prelude::*};
 
 use std::cell::RefCell;
@@ -8,10 +6,10 @@ use std::{thread, time::Duration};
 
 mod main_window;
 mod res;
-mod surface_functions;
-mod wave_model;
 mod wave_widget;
 
+use surface_waves::wave_model::WaveModel;
+
 const REDRAW_DT: u64 = 16;
 
 /// Message to control the simulation
@@ -26,7 +24,7 @@ pub enum Message {
 fn main() {
     let running = false;
 
-    let mut m = wave_model::WaveModel::make_model();
+    let mut m = WaveModel::make_model();
     m.reset();
 
     let a = app::App::default();
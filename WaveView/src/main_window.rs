@@ -1,9 +1,10 @@
 #![allow(clippy::too_many_lines)]
+#![allow(clippy::collapsible_if)]
 
 use fltk::{app, button, enums, group, menu, prelude::*, valuator, window};
 
-use crate::wave_model::WaveModel;
 use crate::wave_widget::WaveWidget;
+use surface_waves::wave_model::WaveModel;
 
 use crate::res::IconsAssets;
 
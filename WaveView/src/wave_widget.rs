@@ -10,27 +10,21 @@ use fltk::{draw, enums, prelude::*, widget, widget_extends};
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use crate::frame_saver::FrameSaver;
-use crate::wave_model::WaveModel;
-
-#[derive(Copy, Clone)]
-struct Area {
-    xmin: f64,
-    xmax: f64,
-    ymin: f64,
-    ymax: f64,
-}
+use mechanics_common::frame_saver::FrameSaver;
+use mechanics_common::plot_area::{Area, PlotTransform};
+use mechanics_common::plot_axes::{self, Ticks};
+use surface_waves::wave_model::WaveModel;
 
 const MARGIN: i32 = 40;
-const TICK_SIZE: i32 = 15;
-const TICKS_COUNT_X: i32 = 20;
-const TICKS_COUNT_Y: i32 = 30;
+const TICKS: Ticks = Ticks {
+    size: 15,
+    count_x: 10,
+    count_y: 10,
+};
 
 pub struct WaveWidget {
     inner: widget::Widget,
-    area: Area,
-    pixel_x: f64,
-    pixel_y: f64,
+    transform: PlotTransform,
     offs: Rc<RefCell<draw::Offscreen>>,
     frame_saver: FrameSaver,
 }
@@ -47,8 +41,13 @@ impl WaveWidget {
             ymin: -1.0,
             ymax: 0.5,
         };
-        let pixel_x = (area.xmax - area.xmin) / (width - MARGIN * 2 - TICK_SIZE) as f64;
-        let pixel_y = (area.ymax - area.ymin) / (height - MARGIN * 2 - TICK_SIZE) as f64;
+        let transform = PlotTransform::new(
+            area,
+            MARGIN + TICKS.size,
+            MARGIN,
+            width - MARGIN * 2 - TICKS.size,
+            height - MARGIN * 2 - TICKS.size,
+        );
 
         let offs = draw::Offscreen::new(width, height).unwrap();
 
@@ -78,20 +77,18 @@ impl WaveWidget {
 
         Self {
             inner,
-            area,
-            pixel_x,
-            pixel_y,
+            transform,
             offs,
             frame_saver,
         }
     }
 
     fn get_x(&self, x: f64) -> f64 {
-        (x - self.area.xmin) / self.pixel_x + (MARGIN + TICK_SIZE) as f64
+        self.transform.get_x(x)
     }
 
     fn get_y(&self, y: f64) -> f64 {
-        (self.area.ymax - y) / self.pixel_y + MARGIN as f64
+        self.transform.get_y(y)
     }
 
     pub fn draw_model(&mut self, m: &WaveModel) {
@@ -99,7 +96,6 @@ impl WaveWidget {
         const AXIS_Y: f64 = 0.0;
         const GRADIENT_COLOR_A: enums::Color = enums::Color::from_u32(0x00_f7_fb_ff);
         const GRADIENT_COLOR_B: enums::Color = enums::Color::from_u32(0x00_08_30_6b);
-        const BOX_COLOR: enums::Color = enums::Color::Black;
         const AXES_COLOR: enums::Color = enums::Color::Black;
         const MODEL_LINES_COLOR: enums::Color = enums::Color::Black;
         const MODEL_POINTS_COLOR: enums::Color = enums::Color::Black;
@@ -108,7 +104,7 @@ impl WaveWidget {
         self.offs.borrow().begin();
 
         let (width, height) = (self.w(), self.h());
-        let area = self.area;
+        let area = self.transform.area();
 
         let scale_x = (area.xmax - area.xmin) / m.delta;
         let scale_z = (AXIS_Y - area.ymin) / m.h;
@@ -123,14 +119,15 @@ impl WaveWidget {
         draw::set_draw_color(TEXT_COLOR);
         draw::set_font(enums::Font::Helvetica, 16);
 
+        // Time is shown at the right end of the x axis, below its title
         let time_str = format!("time: {:.4}", m.time);
         draw::draw_text2(
             &time_str,
-            self.get_x((area.xmax - area.xmin) / 2.0 + area.xmin) as i32,
-            self.get_y(area.ymin) as i32 + TICK_SIZE,
+            self.get_x(area.xmax) as i32,
+            self.get_y(area.ymin) as i32 + TICKS.size + 16,
             0,
             0,
-            enums::Align::Top,
+            enums::Align::TopRight,
         );
 
         // Draw gradient heatmap (lowermost layer)
@@ -158,78 +155,11 @@ impl WaveWidget {
             draw::end_complex_polygon();
         }
 
-        // Draw bounding box
-        draw::set_line_style(draw::LineStyle::Solid, 1);
-        draw::set_draw_color(BOX_COLOR);
-        draw::begin_loop();
-        draw::vertex(self.get_x(area.xmin), self.get_y(area.ymin));
-        draw::vertex(self.get_x(area.xmin), self.get_y(area.ymax));
-        draw::vertex(self.get_x(area.xmax), self.get_y(area.ymax));
-        draw::vertex(self.get_x(area.xmax), self.get_y(area.ymin));
-        draw::end_loop();
-
-        // Ticks
-        let dx = (area.xmax - area.xmin) / TICKS_COUNT_X as f64;
-        for i in 0..=TICKS_COUNT_X {
-            draw::draw_yxline(
-                self.get_x(area.xmin + dx * (i as f64)) as i32,
-                self.get_y(area.ymin) as i32,
-                self.get_y(area.ymin) as i32 + TICK_SIZE / (if i % 2 == 0 { 1 } else { 2 }),
-            );
-        }
-
-        let dy = (area.ymax - area.ymin) / TICKS_COUNT_Y as f64;
-        for i in 0..=TICKS_COUNT_Y {
-            draw::draw_xyline(
-                self.get_x(area.xmin) as i32,
-                self.get_y(area.ymin + dy * (i as f64)) as i32,
-                self.get_x(area.xmin) as i32 - TICK_SIZE / (if i % 2 == 0 { 1 } else { 2 }),
-            );
-        }
-
-        // Draw range labels
-        draw::set_draw_color(TEXT_COLOR);
-        draw::set_font(enums::Font::Helvetica, 14);
-
-        let xmin_str = format!("{:.2}", area.xmin);
-        draw::draw_text2(
-            &xmin_str,
-            self.get_x(area.xmin) as i32,
-            self.get_y(area.ymin) as i32 + TICK_SIZE,
-            0,
-            0,
-            enums::Align::TopLeft,
-        );
-
-        let xmax_str = format!("{:.2}", area.xmax);
-        draw::draw_text2(
-            &xmax_str,
-            self.get_x(area.xmax) as i32,
-            self.get_y(area.ymin) as i32 + TICK_SIZE,
-            0,
-            0,
-            enums::Align::TopRight,
-        );
-
-        let ymin_str = format!("{y_min:.2}");
-        draw::draw_text2(
-            &ymin_str,
-            self.get_x(area.xmin) as i32 - TICK_SIZE - 2,
-            self.get_y(area.ymin) as i32,
-            0,
-            0,
-            enums::Align::Right,
-        );
-
-        let ymax_str = format!("{y_max:.2}");
-        draw::draw_text2(
-            &ymax_str,
-            self.get_x(area.xmin) as i32 - TICK_SIZE - 2,
-            self.get_y(area.ymax) as i32,
-            0,
-            0,
-            enums::Align::Right,
-        );
+        // Draw bounding box and ticks with labels of the model ranges
+        let t = &self.transform;
+        plot_axes::draw_bounding_box(t);
+        plot_axes::draw_axes(t, &TICKS, (area.xmin, area.xmax), (y_min, y_max), false);
+        plot_axes::draw_axis_labels(t, &TICKS, "x", "z");
 
         // Draw axes
         draw::set_line_style(draw::LineStyle::DashDot, 1);
@@ -241,21 +171,6 @@ impl WaveWidget {
             self.get_y(AXIS_Y) as i32,
         );
 
-        draw::set_line_style(draw::LineStyle::Solid, 0);
-
-        draw::set_draw_color(TEXT_COLOR);
-        draw::set_font(enums::Font::Helvetica, 14);
-
-        let yaxis_str = format!("{AXIS_Y:.2}");
-        draw::draw_text2(
-            &yaxis_str,
-            self.get_x(area.xmin) as i32 - TICK_SIZE - 2,
-            self.get_y(AXIS_Y) as i32,
-            0,
-            0,
-            enums::Align::Right,
-        );
-
         // Draw model
         draw::set_line_style(draw::LineStyle::Solid, 1);
         draw::set_draw_color(MODEL_LINES_COLOR);
@@ -300,16 +215,9 @@ impl WaveWidget {
     }
 
     pub fn save_frame(&mut self) {
-        match draw::capture_offscreen(&mut self.offs.borrow_mut(), self.w(), self.h()) {
-            Ok(img) => {
-                let data = img.to_rgb_data();
-                self.frame_saver
-                    .save_frame(&data, img.width(), img.height());
-            }
-            Err(error) => {
-                eprintln!("Cannot capture frame to image. Error: {error}");
-            }
-        }
+        let (w, h) = (self.w(), self.h());
+        self.frame_saver
+            .save_offscreen(&mut self.offs.borrow_mut(), w, h);
     }
 }
 